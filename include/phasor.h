@@ -0,0 +1,252 @@
+#ifndef PHASOR_H
+#define PHASOR_H
+
+/* Warning: this file is autogenerated by cbindgen. Do not modify it manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The floating point category of a phasor, see [`Phasor::classify`].
+ */
+typedef enum PhasorCategory {
+  PHASOR_CATEGORY_NAN,
+  PHASOR_CATEGORY_INFINITE,
+  PHASOR_CATEGORY_ZERO,
+  PHASOR_CATEGORY_SUBNORMAL,
+  PHASOR_CATEGORY_NORMAL,
+} PhasorCategory;
+
+/**
+ * The outcome of a checked operation, see [`PhasorError`].
+ */
+typedef enum PhasorStatus {
+  /**
+   * The operation succeeded.
+   */
+  PHASOR_STATUS_OK,
+  /**
+   * At least one of the operands is _NaN_.
+   */
+  PHASOR_STATUS_NAN_OPERAND,
+  /**
+   * The operation has no well defined result, e.g. `∞ - ∞`, `0 × ∞` or `0 / 0`.
+   */
+  PHASOR_STATUS_INDETERMINATE,
+  /**
+   * The result is infinite because a finite operand was divided by zero.
+   */
+  PHASOR_STATUS_DIVISION_BY_ZERO,
+  /**
+   * The result overflowed to infinity even though all operands are finite.
+   */
+  PHASOR_STATUS_OVERFLOW,
+} PhasorStatus;
+
+/**
+ * The notation used by [`phasor_format`], see [`Notation`].
+ */
+typedef enum PhasorNotation {
+  PHASOR_NOTATION_POLAR,
+  PHASOR_NOTATION_RECT,
+  PHASOR_NOTATION_EXP,
+} PhasorNotation;
+
+/**
+ * The unit of angles written by [`phasor_format`], see [`AngleUnit`].
+ */
+typedef enum PhasorAngleUnit {
+  PHASOR_ANGLE_UNIT_RADIANS,
+  PHASOR_ANGLE_UNIT_DEGREES,
+} PhasorAngleUnit;
+
+typedef struct Phasor {
+  double mag;
+  double tan;
+} Phasor;
+
+/**
+ * Options for [`phasor_format`], see [`Format`].
+ */
+typedef struct PhasorFormat {
+  enum PhasorNotation notation;
+  enum PhasorAngleUnit unit;
+  /**
+   * The number of decimal places, or the shortest representation that round-trips if negative.
+   */
+  int32_t precision;
+  bool engineering;
+} PhasorFormat;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Constructs a phasor from its real and imaginary parts.
+ */
+struct Phasor phasor_rect(double re, double im);
+
+/**
+ * Constructs a phasor from its magnitude and angle in radians.
+ */
+struct Phasor phasor_polar(double mag, double angle);
+
+/**
+ * The real part.
+ */
+double phasor_real(struct Phasor p);
+
+/**
+ * The imaginary part.
+ */
+double phasor_imag(struct Phasor p);
+
+/**
+ * The magnitude.
+ */
+double phasor_norm(struct Phasor p);
+
+/**
+ * The angle in radians.
+ */
+double phasor_angle(struct Phasor p);
+
+/**
+ * The floating point category.
+ */
+enum PhasorCategory phasor_classify(struct Phasor p);
+
+/**
+ * The sum.
+ */
+struct Phasor phasor_add(struct Phasor lhs, struct Phasor rhs);
+
+/**
+ * The difference.
+ */
+struct Phasor phasor_sub(struct Phasor lhs, struct Phasor rhs);
+
+/**
+ * The product.
+ */
+struct Phasor phasor_mul(struct Phasor lhs, struct Phasor rhs);
+
+/**
+ * The quotient.
+ */
+struct Phasor phasor_div(struct Phasor lhs, struct Phasor rhs);
+
+/**
+ * The negation.
+ */
+struct Phasor phasor_neg(struct Phasor p);
+
+/**
+ * The complex conjugate.
+ */
+struct Phasor phasor_conj(struct Phasor p);
+
+/**
+ * The reciprocal.
+ */
+struct Phasor phasor_recip(struct Phasor p);
+
+/**
+ * The exponential.
+ */
+struct Phasor phasor_exp(struct Phasor p);
+
+/**
+ * The natural logarithm.
+ */
+struct Phasor phasor_ln(struct Phasor p);
+
+/**
+ * The principal square root.
+ */
+struct Phasor phasor_sqrt(struct Phasor p);
+
+/**
+ * The hyperbolic sine.
+ */
+struct Phasor phasor_sinh(struct Phasor p);
+
+/**
+ * The hyperbolic cosine.
+ */
+struct Phasor phasor_cosh(struct Phasor p);
+
+/**
+ * The logarithm to an arbitrary base.
+ */
+struct Phasor phasor_log(struct Phasor p, double base);
+
+/**
+ * See [`Phasor::checked_add`].
+ */
+enum PhasorStatus phasor_checked_add(struct Phasor lhs, struct Phasor rhs, struct Phasor *out);
+
+/**
+ * See [`Phasor::checked_sub`].
+ */
+enum PhasorStatus phasor_checked_sub(struct Phasor lhs, struct Phasor rhs, struct Phasor *out);
+
+/**
+ * See [`Phasor::checked_mul`].
+ */
+enum PhasorStatus phasor_checked_mul(struct Phasor lhs, struct Phasor rhs, struct Phasor *out);
+
+/**
+ * See [`Phasor::checked_div`].
+ */
+enum PhasorStatus phasor_checked_div(struct Phasor lhs, struct Phasor rhs, struct Phasor *out);
+
+/**
+ * See [`Phasor::checked_recip`].
+ */
+enum PhasorStatus phasor_checked_recip(struct Phasor p, struct Phasor *out);
+
+/**
+ * See [`Phasor::checked_exp`].
+ */
+enum PhasorStatus phasor_checked_exp(struct Phasor p, struct Phasor *out);
+
+/**
+ * See [`Phasor::checked_ln`].
+ */
+enum PhasorStatus phasor_checked_ln(struct Phasor p, struct Phasor *out);
+
+/**
+ * See [`Phasor::checked_sinh`].
+ */
+enum PhasorStatus phasor_checked_sinh(struct Phasor p, struct Phasor *out);
+
+/**
+ * See [`Phasor::checked_cosh`].
+ */
+enum PhasorStatus phasor_checked_cosh(struct Phasor p, struct Phasor *out);
+
+/**
+ * See [`Phasor::checked_log`].
+ */
+enum PhasorStatus phasor_checked_log(struct Phasor p, double base, struct Phasor *out);
+
+/**
+ * The default options for [`phasor_format`].
+ */
+struct PhasorFormat phasor_format_default(void);
+
+/**
+ * Writes `p` into `buf` following `format`, or the defaults if `NULL`, and returns its length.
+ */
+size_t phasor_format(struct Phasor p, const struct PhasorFormat *format, char *buf, size_t len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* PHASOR_H */
@@ -0,0 +1,12 @@
+import { supportsSimd } from './simd.js';
+import { __wbg_set_wasm } from '../dist/bundler/phasor_bg.js';
+
+// Both builds share the same bindings, so only the wasm itself needs to be picked.
+const wasm = supportsSimd()
+  ? await import('../dist/bundler/phasor_simd_bg.wasm')
+  : await import('../dist/bundler/phasor_bg.wasm');
+
+__wbg_set_wasm(wasm);
+wasm.__wbindgen_start?.();
+
+export * from '../dist/bundler/phasor_bg.js';
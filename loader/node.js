@@ -0,0 +1,6 @@
+const { supportsSimd } = require('./simd.js');
+
+// PHASOR_WASM=simd or PHASOR_WASM=scalar overrides the feature test.
+const simd = process.env.PHASOR_WASM ? process.env.PHASOR_WASM === 'simd' : supportsSimd();
+
+module.exports = simd ? require('../dist/nodejs-simd/phasor.js') : require('../dist/nodejs/phasor.js');
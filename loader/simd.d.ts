@@ -0,0 +1,2 @@
+/** Whether the host supports the SIMD128 WebAssembly feature. */
+export function supportsSimd(): boolean;
@@ -0,0 +1,11 @@
+// The smallest module using a SIMD128 instruction, which only validates where SIMD128 is supported.
+const probe = new Uint8Array([
+  0, 97, 115, 109, 1, 0, 0, 0, 1, 5, 1, 96, 0, 1, 123, 3, 2, 1, 0, 10, 10, 1, 8, 0, 65, 0, 253, 15,
+  253, 98, 11,
+]);
+
+function supportsSimd() {
+  return typeof WebAssembly === 'object' && WebAssembly.validate(probe);
+}
+
+module.exports = { supportsSimd };
@@ -0,0 +1,52 @@
+from phasor import polar, rect
+from util import close_to, div, samples
+
+
+def test_add():
+    for _, _, a, b in samples:
+        for _, _, c, d in samples:
+            u, v = rect(a, b), rect(c, d)
+
+            if not u.ulps_eq(-v, 1e-15):
+                assert close_to(u + v, rect(a + c, b + d), 8)
+
+
+def test_sub():
+    for _, _, a, b in samples:
+        for _, _, c, d in samples:
+            u, v = rect(a, b), rect(c, d)
+
+            if not u.ulps_eq(v, 1e-15):
+                assert close_to(u - v, rect(a - c, b - d), 8)
+
+
+def test_mul():
+    for a, b, _, _ in samples:
+        for c, d, _, _ in samples:
+            assert close_to(polar(a, b) * polar(c, d), polar(a * c, b + d), 4)
+
+
+def test_div():
+    for a, b, _, _ in samples:
+        for c, d, _, _ in samples:
+            u, v = polar(a, b), polar(c, d)
+
+            if not u.is_zero() or not v.is_zero():
+                assert close_to(u / v, polar(div(a, c), b - d), 4)
+
+
+def test_neg():
+    for mag, ang, re, im in samples:
+        assert close_to(-rect(re, im), rect(-re, -im))
+        assert close_to(-polar(mag, ang), polar(-mag, ang))
+
+
+def test_conj():
+    for mag, ang, re, im in samples:
+        assert close_to(rect(re, im).conj(), rect(re, -im))
+        assert close_to(polar(mag, ang).conj(), polar(mag, -ang))
+
+
+def test_recip():
+    for mag, ang, _, _ in samples:
+        assert close_to(polar(mag, ang).recip(), polar(div(1, mag), -ang))
@@ -0,0 +1,64 @@
+import math
+from decimal import Decimal
+
+import pytest
+
+from phasor import polar, rect
+from util import samples
+
+
+def number(x):
+    """Formats `x` as Rust's shortest round-trip representation does."""
+    if math.isnan(x):
+        return "NaN"
+
+    if math.isinf(x):
+        return "inf" if x > 0 else "-inf"
+
+    return format(Decimal(repr(x)), "f").removesuffix(".0")
+
+
+def test_polar_notation():
+    for _, _, re, im in samples:
+        p = rect(re, im)
+        assert p.format() == f"{number(p.norm())}∠{number(p.angle())}"
+        assert p.format(notation="polar") == f"{number(p.norm())}∠{number(p.angle())}"
+
+
+def test_repr_is_display():
+    for _, _, re, im in samples:
+        p = rect(re, im)
+        assert repr(p) == str(p) == p.format()
+
+
+def test_precision():
+    for _, _, re, im in samples:
+        p = rect(re, im)
+        assert p.format(precision=3) == f"{p.norm():.3f}∠{p.angle():.3f}"
+
+
+def test_rect_notation():
+    p = rect(3, -4)
+    assert p.format(notation="rect", precision=1) == "3.0-4.0i"
+
+
+def test_degrees():
+    assert polar(5, math.pi / 2).format(unit="deg") == "5∠90°"
+    assert polar(5, -math.pi / 6).format(unit="deg", precision=1) == "5.0∠-30.0°"
+
+
+def test_engineering():
+    p = polar(1200, -math.pi / 6)
+    assert p.format(unit="deg", precision=1, engineering=True) == "1.2k∠-30.0°"
+    assert rect(3000, -4e6).format(notation="rect", precision=1, engineering=True) == "3.0k-4.0Mi"
+
+
+def test_invalid_options():
+    with pytest.raises(ValueError):
+        rect(1).format(notation="spherical")
+
+    with pytest.raises(ValueError):
+        rect(1).format(unit="turn")
+
+    with pytest.raises(OverflowError):
+        rect(1).format(precision=-1)
@@ -0,0 +1,58 @@
+import math
+
+from phasor import polar, rect
+from util import close_to, log, samples
+
+
+def test_exp():
+    for mag, ang, _, _ in samples:
+        assert close_to(rect(log(mag), ang).exp(), polar(mag, ang), 40)
+
+
+def test_ln():
+    for mag, ang, _, _ in samples:
+        r = rect(log(mag), math.atan2(math.sin(ang), math.cos(ang)))
+        assert close_to(polar(mag, ang).ln(), r)
+
+
+def test_log():
+    for mag, ang, _, _ in samples:
+        r = rect(log(mag) / math.log(10), math.atan2(math.sin(ang), math.cos(ang)) / math.log(10))
+        assert close_to(polar(mag, ang).log(10), r)
+
+
+def test_sinh():
+    for mag, ang, _, _ in samples:
+        u = rect(log(mag), ang)
+
+        if not u.is_zero():
+            s = polar(mag, ang)
+            assert close_to(u.sinh(), s / rect(2) - rect(0.5) / s, 40)
+
+
+def test_cosh():
+    for mag, ang, _, _ in samples:
+        s = polar(mag, ang)
+        assert close_to(rect(log(mag), ang).cosh(), s / rect(2) + rect(0.5) / s, 40)
+
+
+def test_ln_branch():
+    # The logarithm of zero is -∞ on every branch.
+    for mag, _, re, im in samples[1:]:
+        for k in [-2, -1, 0, 1, 2]:
+            p = rect(re, im).ln_branch(k)
+            assert close_to(p.real(), log(mag))
+            assert close_to(p.imag(), rect(re, im).angle() + 2 * math.pi * k, 8)
+
+
+def test_sqrt():
+    for mag, ang, _, _ in samples:
+        p = polar(mag, ang).sqrt()
+        assert close_to(p, polar(math.sqrt(mag), polar(mag, ang).angle() / 2), 8)
+        assert p.real() >= 0
+
+
+def test_sqrt_branch():
+    for _, _, re, im in samples:
+        assert close_to(rect(re, im).sqrt_branch(1), -rect(re, im).sqrt())
+        assert close_to(rect(re, im).sqrt_branch(2), rect(re, im).sqrt())
@@ -0,0 +1,84 @@
+import math
+import pickle
+
+import pytest
+
+from phasor import Phasor, polar, rect
+from util import close_to, samples
+
+
+def test_operators():
+    for _, _, a, b in samples:
+        for _, _, c, d in samples[::7]:
+            u, v = rect(a, b), rect(c, d)
+            assert (u + v).ulps_eq(u + complex(c, d), 0, 0)
+            assert (u - v).ulps_eq(complex(a, b) - v, 0, 0)
+            assert (u * v).ulps_eq(u * complex(c, d), 0, 0)
+            assert (u / v).ulps_eq(complex(a, b) / v, 0, 0) or (u / v).is_nan()
+
+
+def test_mixed_with_real_numbers():
+    assert close_to(rect(3, 4) + 1, rect(4, 4))
+    assert close_to(2 - rect(3, 4), rect(-1, -4))
+    assert close_to(rect(3, 4) * 2.0, rect(6, 8))
+    assert close_to(1 / rect(0, 2), rect(0, -0.5))
+
+
+def test_unary_operators():
+    p = rect(3, 4)
+    assert close_to(-p, rect(-3, -4))
+    assert +p == p
+    assert abs(p) == 5
+
+
+def test_complex():
+    for _, _, re, im in samples:
+        c = complex(rect(re, im))
+        assert close_to(c.real, re)
+        assert close_to(c.imag, im)
+
+
+def test_division_by_zero_follows_phasor():
+    assert (rect(1) / 0).is_infinite()
+    assert (rect(0) / 0).is_nan()
+    assert Phasor.ZERO.recip().is_infinite()
+
+
+def test_equality():
+    assert rect(3, 4) == rect(3, 4)
+    assert rect(3, 4) == 3 + 4j
+    assert rect(3, 4) != rect(4, 3)
+    assert Phasor.NAN != Phasor.NAN
+
+
+def test_truthiness():
+    assert not Phasor.ZERO
+    assert Phasor.ONE
+    assert Phasor.NAN
+
+
+def test_immutable_and_unhashable():
+    with pytest.raises(AttributeError):
+        Phasor.ONE.mag = 2
+
+    with pytest.raises(TypeError):
+        hash(Phasor.ONE)
+
+
+def test_pickle():
+    for mag, ang, _, _ in samples:
+        p = polar(mag, ang)
+        q = pickle.loads(pickle.dumps(p))
+        assert (q.mag, q.tan) == (p.mag, p.tan)
+
+
+def test_unsupported_operands():
+    with pytest.raises(TypeError):
+        rect(1) + "1"
+
+    with pytest.raises(TypeError):
+        rect(1) * [1]
+
+
+def test_nan_is_preserved():
+    assert math.isnan(complex(Phasor.NAN).real)
@@ -0,0 +1,103 @@
+import math
+
+import numpy as np
+import pytest
+
+import phasor
+from phasor import polar, rect
+from util import samples
+
+values = np.array([complex(re, im) for _, _, re, im in samples])
+others = values[::-1].copy()
+
+
+def parts(p):
+    return (p.mag, p.tan)
+
+
+def phasors(a):
+    return [rect(c.real, c.imag) for c in np.ravel(a)]
+
+
+def same(a, ps):
+    """Whether the elements of `a` are exactly the rectangular parts of the phasors `ps`."""
+    expected = np.array([complex(*p.to_rect()) for p in ps])
+    return a.dtype == np.complex128 and np.array_equal(np.ravel(a), expected, equal_nan=True)
+
+
+def test_binary_operations():
+    ops = [
+        (phasor.add, lambda p, q: p + q),
+        (phasor.sub, lambda p, q: p - q),
+        (phasor.mul, lambda p, q: p * q),
+        (phasor.div, lambda p, q: p / q),
+    ]
+
+    for batch, op in ops:
+        r = batch(values, others)
+        assert same(r, [op(p, q) for p, q in zip(phasors(values), phasors(others))])
+
+
+def test_unary_operations():
+    ops = [
+        (phasor.neg, lambda p: -p),
+        (phasor.conj, lambda p: p.conj()),
+        (phasor.recip, lambda p: p.recip()),
+        (phasor.exp, lambda p: p.exp()),
+        (phasor.ln, lambda p: p.ln()),
+        (phasor.sqrt, lambda p: p.sqrt()),
+        (phasor.sinh, lambda p: p.sinh()),
+        (phasor.cosh, lambda p: p.cosh()),
+    ]
+
+    for batch, op in ops:
+        assert same(batch(values), [op(p) for p in phasors(values)])
+
+
+def test_logarithm_to_arbitrary_base():
+    assert same(phasor.log(values, 10), [p.log(10) for p in phasors(values)])
+
+
+def test_broadcast_single_element():
+    ps = phasors(values)
+    assert same(phasor.mul(values, 2j), [p * rect(0, 2) for p in ps])
+    assert same(phasor.sub(2j, values), [rect(0, 2) - p for p in ps])
+
+
+def test_inputs_are_left_untouched():
+    copy = values.copy()
+    phasor.exp(values)
+    assert np.array_equal(values, copy)
+
+
+def test_shape_is_preserved():
+    a = values[:32].reshape(4, 8)
+    assert phasor.exp(a).shape == (4, 8)
+    assert phasor.add(a, a).shape == (4, 8)
+    assert phasor.norm(a).shape == (4, 8)
+
+
+def test_non_contiguous_and_real_inputs():
+    assert same(phasor.exp(values[::3]), [p.exp() for p in phasors(values[::3])])
+    assert same(phasor.exp([1.0, 2.0]), [rect(1).exp(), rect(2).exp()])
+
+
+def test_norms_and_angles():
+    for (mag, ang, _, _), n, a in zip(samples, phasor.norm(values), phasor.angle(values)):
+        assert polar(n).ulps_eq(polar(mag).norm())
+        assert polar(a).ulps_eq(rect(*polar(mag, ang).to_rect()).angle(), 1e-12)
+
+
+def test_edge_cases_follow_phasor():
+    edges = np.array([complex(math.inf, 0), complex(0, math.inf), complex(math.inf, math.inf), 0j])
+    assert same(phasor.exp(edges), [p.exp() for p in phasors(edges)])
+    assert same(phasor.recip(edges), [p.recip() for p in phasors(edges)])
+    assert same(phasor.div(edges, 0j), [p / rect(0) for p in phasors(edges)])
+
+
+def test_mismatched_shapes():
+    with pytest.raises(ValueError):
+        phasor.add(values[:4], values[:6])
+
+    with pytest.raises(ValueError):
+        phasor.add(values[:6].reshape(2, 3), values[:6].reshape(3, 2))
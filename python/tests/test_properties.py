@@ -0,0 +1,88 @@
+import math
+
+from phasor import Phasor, cis, i, polar, rect
+from util import close_to, samples
+
+
+def test_constructor():
+    for _, _, re, im in samples:
+        p = rect(re, im)
+        assert close_to(Phasor(p.mag, p.tan), p)
+        assert close_to(Phasor(re), rect(re))
+        assert close_to(Phasor(im, math.inf), i(im))
+
+
+def test_constants():
+    assert Phasor.ZERO.is_zero()
+    assert Phasor.ONE.ulps_eq(rect(1))
+    assert Phasor.I.ulps_eq(i(1))
+    assert Phasor.NEG_ONE.ulps_eq(rect(-1))
+    assert Phasor.NEG_I.ulps_eq(i(-1))
+    assert Phasor.INFINITY.is_infinite()
+    assert Phasor.INFINITY.is_real()
+    assert Phasor.NAN.is_nan()
+
+
+def test_imaginary_unit():
+    for _, _, _, im in samples:
+        assert close_to(i(im), polar(im, math.pi / 2))
+
+
+def test_real():
+    for _, _, re, im in samples:
+        assert close_to(rect(re).real(), re)
+        assert close_to(rect(re, im).real(), re)
+
+
+def test_imag():
+    for _, _, re, im in samples:
+        assert close_to(rect(re).imag(), 0)
+        assert close_to(rect(re, im).imag(), im)
+
+
+def test_norm():
+    for mag, ang, _, _ in samples:
+        assert close_to(polar(mag).norm(), abs(mag))
+        assert close_to(polar(mag, ang).norm(), abs(mag))
+
+
+def test_angle():
+    for mag, ang, _, _ in samples:
+        r = math.atan2(math.sin(ang), math.cos(ang)) if mag else 0
+        assert close_to(polar(mag).angle(), 0)
+        assert close_to(polar(mag, ang).angle(), r)
+
+
+def test_convert():
+    for mag, ang, re, im in samples:
+        assert close_to(cis(ang), polar(1, ang))
+
+        x, y = rect(re, im).to_rect()
+        assert close_to(x, re)
+        assert close_to(y, im)
+
+        r, a = polar(mag, ang).to_polar()
+        assert close_to(r, mag)
+        assert close_to(polar(r, a), polar(mag, ang), 8)
+
+
+def test_classify():
+    for mag, ang, re, im in samples:
+        p = rect(re, im)
+        assert p.is_nan() == (math.isnan(re) or math.isnan(im))
+        assert p.is_infinite() == (math.isinf(re) or math.isinf(im))
+        assert p.is_finite() == (math.isfinite(re) and math.isfinite(im))
+        assert p.is_zero() == (re == 0 and im == 0)
+        assert polar(mag, ang).is_subnormal() == polar(mag).is_subnormal()
+        assert polar(mag, ang).is_normal() == polar(mag).is_normal()
+        assert p.is_real() == (im == 0)
+        assert p.is_imaginary() == (re == 0 and im != 0)
+
+
+def test_approx():
+    for _, _, a, b in samples:
+        for _, _, c, d in samples:
+            expected = a == c and b == d
+            assert rect(a, b).abs_diff_eq(rect(c, d)) == expected
+            assert rect(a, b).relative_eq(rect(c, d)) == expected
+            assert rect(a, b).ulps_eq(rect(c, d)) == expected
@@ -0,0 +1,38 @@
+import math
+import sys
+
+from phasor import Phasor, polar
+
+EPSILON = sys.float_info.epsilon
+
+samples = [(0.0, 0.0, 0.0, 0.0)] + [
+    (mag, ang, math.cos(ang) * mag, math.sin(ang) * mag)
+    for mag in [1e-12, 1e-9, 1e-6, 1e-3, 1.0, 1e3, 1e6, 1e9, 1e12]
+    for ang in (math.pi / 16 * k - math.pi for k in range(33))
+]
+
+
+def close_to(x, y, ulps=None):
+    p = x if isinstance(x, Phasor) else polar(x)
+    q = y if isinstance(y, Phasor) else polar(y)
+
+    if ulps is None:
+        return p.ulps_eq(q)
+
+    return p.ulps_eq(q, ulps * EPSILON, ulps)
+
+
+def div(x, y):
+    """Divides as IEEE 754 does, rather than raising on division by zero."""
+    if y:
+        return x / y
+
+    if x and not math.isnan(x):
+        return math.copysign(math.inf, x) * math.copysign(1.0, y)
+
+    return math.nan
+
+
+def log(x):
+    """The natural logarithm, which is -∞ at zero, as in IEEE 754."""
+    return math.log(x) if x else -math.inf
@@ -0,0 +1,135 @@
+import {
+  Phasor,
+  addBatch,
+  angleBatch,
+  conjBatch,
+  coshBatch,
+  divBatch,
+  expBatch,
+  lnBatch,
+  logBatch,
+  mulBatch,
+  negBatch,
+  normBatch,
+  phasorToRectBatch,
+  polar,
+  recipBatch,
+  rect,
+  rectToPhasorBatch,
+  sinhBatch,
+  sqrtBatch,
+  subBatch,
+} from '../';
+import { samples } from './util';
+
+const phasors = samples.map(({ re, im }) => rect(re, im));
+const others = [...phasors].reverse();
+
+const pack = (ps: Phasor[]) => Float64Array.from(ps.flatMap((p) => [p.mag, p.tan]));
+const unpack = (buf: Float64Array) =>
+  Array.from({ length: buf.length / 2 }, (_, k) => new Phasor(buf[2 * k], buf[2 * k + 1]));
+
+// Batch operations yield exactly the same results as their scalar counterparts.
+const parts = (p: Phasor) => [p.mag, p.tan];
+
+describe('Batch', () => {
+  it('should apply binary operations in place', () => {
+    const ops = [
+      [addBatch, (p: Phasor, q: Phasor) => p.add(q)],
+      [subBatch, (p: Phasor, q: Phasor) => p.sub(q)],
+      [mulBatch, (p: Phasor, q: Phasor) => p.mul(q)],
+      [divBatch, (p: Phasor, q: Phasor) => p.div(q)],
+    ] as const;
+
+    ops.forEach(([batch, op]) => {
+      const buf = pack(phasors);
+      batch(buf, pack(others));
+
+      unpack(buf).forEach((r, k) => {
+        expect(parts(r)).toEqual(parts(op(phasors[k], others[k])));
+      });
+    });
+  });
+
+  it('should apply unary operations in place', () => {
+    const ops = [
+      [negBatch, (p: Phasor) => p.neg()],
+      [conjBatch, (p: Phasor) => p.conj()],
+      [recipBatch, (p: Phasor) => p.recip()],
+      [expBatch, (p: Phasor) => p.exp()],
+      [lnBatch, (p: Phasor) => p.ln()],
+      [sqrtBatch, (p: Phasor) => p.sqrt()],
+      [sinhBatch, (p: Phasor) => p.sinh()],
+      [coshBatch, (p: Phasor) => p.cosh()],
+    ] as const;
+
+    ops.forEach(([batch, op]) => {
+      const buf = pack(phasors);
+      batch(buf);
+
+      unpack(buf).forEach((r, k) => {
+        expect(parts(r)).toEqual(parts(op(phasors[k])));
+      });
+    });
+  });
+
+  it('should compute logarithms to an arbitrary base', () => {
+    const buf = pack(phasors);
+    logBatch(buf, 10);
+
+    unpack(buf).forEach((r, k) => {
+      expect(parts(r)).toEqual(parts(phasors[k].log(10)));
+    });
+  });
+
+  it('should broadcast a single phasor', () => {
+    const buf = pack(phasors);
+    mulBatch(buf, pack([rect(0, 2)]));
+
+    unpack(buf).forEach((r, k) => {
+      expect(parts(r)).toEqual(parts(phasors[k].mul(rect(0, 2))));
+    });
+  });
+
+  it('should operate on rectangular pairs', () => {
+    const buf = Float64Array.from(samples.flatMap(({ re, im }) => [re, im]));
+    expBatch(buf, 'rect');
+
+    samples.forEach(({ re, im }, k) => {
+      expect(rect(buf[2 * k], buf[2 * k + 1])).toBeCloseTo(rect(re, im).exp(), 40);
+    });
+  });
+
+  it('should convert between layouts', () => {
+    const buf = Float64Array.from(samples.flatMap(({ re, im }) => [re, im]));
+    rectToPhasorBatch(buf);
+    expect(unpack(buf).map(parts)).toEqual(phasors.map(parts));
+
+    phasorToRectBatch(buf);
+    samples.forEach(({ re, im }, k) => {
+      expect(buf[2 * k]).toBeCloseTo(re);
+      expect(buf[2 * k + 1]).toBeCloseTo(im);
+    });
+  });
+
+  it('should extract norms and angles', () => {
+    const buf = Float64Array.from(samples.flatMap(({ mag, ang }) => {
+      const p = polar(mag, ang);
+      return [p.mag, p.tan];
+    }));
+
+    const norms = normBatch(buf);
+    const angles = angleBatch(buf);
+
+    samples.forEach(({ mag, ang }, k) => {
+      expect(norms[k]).toBeCloseTo(polar(mag, ang).norm());
+      expect(angles[k]).toBeCloseTo(polar(mag, ang).angle());
+    });
+  });
+
+  it('should reject buffers of mismatched lengths', () => {
+    expect(() => addBatch(new Float64Array(3), new Float64Array(2))).toThrow();
+    expect(() => addBatch(new Float64Array(4), new Float64Array(6))).toThrow();
+    expect(() => expBatch(new Float64Array(2), 'polar' as any)).toThrow();
+  });
+});
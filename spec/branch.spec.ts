@@ -0,0 +1,29 @@
+import { polar, rect } from '../';
+import { samples } from './util';
+
+describe('Phasor', () => {
+  it('should have a logarithm on every branch', () => {
+    samples.forEach(({ mag, ang, re, im }) => {
+      [-2, -1, 0, 1, 2].forEach((k) => {
+        const p = rect(re, im).lnBranch(k);
+        expect(p.real()).toBeCloseTo(Math.log(mag));
+        expect(p.imag()).toBeCloseTo(rect(re, im).angle() + 2 * Math.PI * k, 8);
+      });
+    });
+  });
+
+  it('should have a principal square root', () => {
+    samples.forEach(({ mag, ang }) => {
+      const p = polar(mag, ang).sqrt();
+      expect(p).toBeCloseTo(polar(Math.sqrt(mag), polar(mag, ang).angle() / 2), 8);
+      expect(p.real()).toBeGreaterThanOrEqual(0);
+    });
+  });
+
+  it('should have a square root on every branch', () => {
+    samples.forEach(({ re, im }) => {
+      expect(rect(re, im).sqrtBranch(1)).toBeCloseTo(rect(re, im).sqrt().neg());
+      expect(rect(re, im).sqrtBranch(2)).toBeCloseTo(rect(re, im).sqrt());
+    });
+  });
+});
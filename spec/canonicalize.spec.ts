@@ -0,0 +1,33 @@
+import { i, Phasor, rect } from '../';
+import { samples } from './util';
+
+describe('Phasor', () => {
+  it('should canonicalize without changing value', () => {
+    samples.forEach(({ re, im }) => {
+      const p = rect(re, im);
+      if (!p.isZero()) {
+        expect(p.canonicalize()).toBeCloseTo(p);
+      }
+    });
+  });
+
+  const encoding = (p: Phasor) => {
+    const { mag, tan } = p.canonicalize();
+    return { mag, tan };
+  };
+
+  it('should have a unique canonical encoding', () => {
+    expect(encoding(new Phasor(1, -0))).toEqual(encoding(new Phasor(1, 0)));
+    expect(encoding(new Phasor(-2, -Infinity))).toEqual(encoding(i(2)));
+    expect(encoding(new Phasor(-0, 1))).toEqual(encoding(new Phasor(0, 0)));
+    expect(encoding(new Phasor(NaN, 1))).toEqual(encoding(new Phasor(1, NaN)));
+  });
+
+  it('should map negative reals to the upper side of the branch cut', () => {
+    [new Phasor(-1, 0), new Phasor(-1, -0)].forEach((p) => {
+      expect(p.canonicalize().angle()).toBe(Math.PI);
+    });
+
+    expect(encoding(new Phasor(-1, 0))).toEqual(encoding(rect(-1, 0)));
+  });
+});
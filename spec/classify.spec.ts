@@ -41,6 +41,25 @@ describe('Phasor', () => {
     });
   });
 
+  it('should be classifiable', () => {
+    expect(rect(NaN, 1).classify()).toBe('nan');
+    expect(rect(Infinity, 1).classify()).toBe('infinite');
+    expect(rect(0, 0).classify()).toBe('zero');
+    expect(rect(1E-315, 0).classify()).toBe('subnormal');
+    expect(rect(3, 4).classify()).toBe('normal');
+  });
+
+  it('should classify consistently with its predicates', () => {
+    samples.forEach(({ re, im }) => {
+      const p = rect(re, im);
+      expect(p.classify() === 'nan').toBe(p.isNaN());
+      expect(p.classify() === 'infinite').toBe(p.isInfinite());
+      expect(p.classify() === 'zero').toBe(p.isZero());
+      expect(p.classify() === 'subnormal').toBe(p.isSubnormal());
+      expect(p.classify() === 'normal').toBe(p.isNormal());
+    });
+  });
+
   it('should have predicate to check whether real', () => {
     samples.forEach(({ re, im }) => {
       expect(rect(re, im).isReal()).toBe(im === 0);
@@ -1,4 +1,4 @@
-import { i, Phasor, rect } from '../';
+import { i, Phasor, polar, rect } from '../';
 import { samples } from './util';
 
 describe('Phasor', () => {
@@ -10,4 +10,18 @@ describe('Phasor', () => {
       expect(new Phasor(im, Infinity)).toBeCloseTo(i(im));
     });
   });
+
+  it('should be constructible given rectangular coordinates', () => {
+    samples.forEach(({ re, im }) => {
+      expect(Phasor.rect(re, im).toJSON()).toEqual(rect(re, im).toJSON());
+      expect(Phasor.rect(re).toJSON()).toEqual(rect(re).toJSON());
+    });
+  });
+
+  it('should be constructible given polar coordinates', () => {
+    samples.forEach(({ mag, ang }) => {
+      expect(Phasor.polar(mag, ang).toJSON()).toEqual(polar(mag, ang).toJSON());
+      expect(Phasor.polar(mag).toJSON()).toEqual(polar(mag).toJSON());
+    });
+  });
 });
@@ -0,0 +1,22 @@
+import { Phasor, i, rect } from '../';
+
+describe('Phasor', () => {
+  it('should have constants', () => {
+    expect(Phasor.ZERO.isZero()).toBe(true);
+    expect(Phasor.ONE.ulpsEq(rect(1))).toBe(true);
+    expect(Phasor.I.ulpsEq(i(1))).toBe(true);
+    expect(Phasor.NEG_ONE.ulpsEq(rect(-1))).toBe(true);
+    expect(Phasor.NEG_ONE.angle()).toBe(rect(-1).angle());
+    expect(Phasor.NEG_I.ulpsEq(i(-1))).toBe(true);
+    expect(Phasor.INFINITY.isInfinite()).toBe(true);
+    expect(Phasor.INFINITY.isReal()).toBe(true);
+    expect(Phasor.NAN.isNaN()).toBe(true);
+  });
+
+  it('should return fresh constants', () => {
+    const one = Phasor.ONE;
+    one.mag = 2;
+
+    expect(Phasor.ONE.mag).toBe(1);
+  });
+});
@@ -0,0 +1,26 @@
+import { cis, polar, rect } from '../';
+import { samples } from './util';
+
+describe('Phasor', () => {
+  it('should be constructible from an angle', () => {
+    samples.forEach(({ ang }) => {
+      expect(cis(ang)).toBeCloseTo(polar(1, ang));
+    });
+  });
+
+  it('should be convertible to rectangular form', () => {
+    samples.forEach(({ re, im }) => {
+      const [x, y] = rect(re, im).toRect();
+      expect(x).toBeCloseTo(re);
+      expect(y).toBeCloseTo(im);
+    });
+  });
+
+  it('should be convertible to polar form', () => {
+    samples.forEach(({ mag, ang }) => {
+      const [r, a] = polar(mag, ang).toPolar();
+      expect(r).toBeCloseTo(mag);
+      expect(polar(r, a)).toBeCloseTo(polar(mag, ang));
+    });
+  });
+});
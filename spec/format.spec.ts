@@ -0,0 +1,72 @@
+import { polar, rect } from '../';
+import { samples } from './util';
+
+describe('Phasor', () => {
+  it('should be formattable in polar notation', () => {
+    expect(rect(3, 4).format()).toEqual('5∠0.9272952180016122');
+    expect(rect(3, 4).format({ notation: 'polar' })).toEqual('5∠0.9272952180016122');
+    expect(rect(-1).format()).toEqual('1∠3.141592653589793');
+    expect(rect(0, -1E-9).format()).toEqual('0.000000001∠-1.5707963267948966');
+    expect(rect(2E12, -0).format()).toEqual('2000000000000∠-0');
+  });
+
+  it('should be formattable in rectangular notation', () => {
+    expect(rect(3, 4).format({ notation: 'rect' })).toEqual('3+4i');
+    expect(rect(-1).format({ notation: 'rect' })).toEqual('-1+0i');
+    expect(rect(0, -1E-9).format({ notation: 'rect' })).toEqual('0-0.000000001i');
+    expect(rect(2E12, -0).format({ notation: 'rect' })).toEqual('2000000000000-0i');
+  });
+
+  it('should never use exponents unless asked to', () => {
+    samples.forEach(({ re, im }) => {
+      const p = rect(re, im);
+      expect(p.format()).not.toMatch(/e/);
+      expect(p.format({ notation: 'rect' })).not.toMatch(/e/);
+    });
+  });
+
+  it('should be formattable with a given precision', () => {
+    samples.forEach(({ re, im }) => {
+      const p = rect(re, im);
+      expect(p.format({ precision: 3 })).toEqual(`${p.norm().toFixed(3)}∠${p.angle().toFixed(3)}`);
+    });
+  });
+
+  it('should be formattable in degrees', () => {
+    expect(polar(5, Math.PI / 2).format({ unit: 'deg' })).toEqual('5∠90°');
+    expect(polar(5, -Math.PI / 6).format({ unit: 'deg', precision: 1 })).toEqual('5.0∠-30.0°');
+  });
+
+  it('should be formattable in engineering notation', () => {
+    const p = polar(1200, -Math.PI / 6);
+    expect(p.format({ unit: 'deg', precision: 1, engineering: true })).toEqual('1.2k∠-30.0°');
+    expect(rect(3000, -4E6).format({ notation: 'rect', precision: 1, engineering: true })).toEqual('3.0k-4.0Mi');
+  });
+
+  it('should reject invalid options', () => {
+    expect(() => rect(1).format({ notation: 'spherical' } as any)).toThrow();
+    expect(() => rect(1).format({ unit: 'turn' } as any)).toThrow();
+    expect(() => rect(1).format({ precision: -1 })).toThrow();
+    expect(() => rect(1).format({ precision: 1.5 })).toThrow();
+  });
+
+  it('should be convertible to string', () => {
+    samples.forEach(({ re, im }) => {
+      const p = rect(re, im);
+      expect(p.toString()).toEqual(p.format());
+      expect(`${p}`).toEqual(p.format());
+      expect(p.toString({ notation: 'rect', precision: 2 })).toEqual(p.format({ notation: 'rect', precision: 2 }));
+    });
+
+    expect(() => rect(1).toString({ unit: 'turn' } as any)).toThrow();
+  });
+
+  it('should be formattable in exponential notation', () => {
+    expect(rect(1200).toExponential()).toEqual('1.2e3∠0e0');
+    expect(polar(0.05, -Math.PI / 4).toExponential(2)).toEqual('5.00e-2∠-7.85e-1');
+    expect(rect(1E-12, 1E-12).toExponential()).toEqual('1.414213562373095e-12∠7.853981633974483e-1');
+    expect(() => rect(1).toExponential(-1)).toThrow();
+    expect(() => rect(1).toExponential(101)).toThrow();
+    expect(() => rect(1).toExponential(2.5)).toThrow();
+  });
+});
@@ -1,15 +1,69 @@
-import { rect } from '../';
+import { i, Phasor, rect } from '../';
 import { samples } from './util';
 
+const specials = [0, 1, -2.5, Infinity, -Infinity, NaN];
+
 describe('Phasor', () => {
   it('should be serializable', () => {
     samples.forEach(({ re, im }) => {
       const p = rect(re, im);
-      expect(p.toString()).toEqual(JSON.stringify(p));
+      expect(JSON.stringify(p)).toEqual(JSON.stringify({ mag: p.mag, tan: p.tan }));
       expect(JSON.parse(JSON.stringify(p))).toEqual({
         mag: p.mag,
         tan: p.tan,
       });
     });
   });
+
+  it('should serialize non-finite fields as strings', () => {
+    expect(JSON.stringify(i(1))).toEqual('{"mag":1,"tan":"Infinity"}');
+    expect(JSON.stringify(new Phasor(-Infinity, 0))).toEqual('{"mag":"-Infinity","tan":0}');
+    expect(JSON.stringify(Phasor.NAN)).toEqual('{"mag":"NaN","tan":"NaN"}');
+  });
+
+  it('should be deserializable', () => {
+    samples.forEach(({ re, im }) => {
+      const p = rect(re, im);
+      const q = Phasor.fromJSON(JSON.parse(JSON.stringify(p)));
+      expect(q.mag).toBe(p.mag);
+      expect(q.tan).toBe(p.tan);
+    });
+  });
+
+  it('should round-trip non-finite fields', () => {
+    specials.forEach((mag) => {
+      specials.forEach((tan) => {
+        const q = Phasor.fromJSON(JSON.parse(JSON.stringify(new Phasor(mag, tan))));
+        expect(q.mag).toBe(mag);
+        expect(q.tan).toBe(tan);
+      });
+    });
+  });
+
+  it('should be revivable by JSON.parse', () => {
+    const json = JSON.stringify({ p: rect(3, 4), ps: [i(2), 1], other: { mag: 1 }, s: 'mag' });
+    const { p, ps, other, s } = JSON.parse(json, Phasor.reviver);
+
+    expect(p).toBeInstanceOf(Phasor);
+    expect(p.ulpsEq(rect(3, 4))).toBe(true);
+    expect(ps[0]).toBeInstanceOf(Phasor);
+    expect(ps[0].tan).toBe(Infinity);
+    expect(ps[1]).toBe(1);
+    expect(other).toEqual({ mag: 1 });
+    expect(s).toBe('mag');
+  });
+
+  it('should reject values of the wrong shape', () => {
+    expect(() => Phasor.fromJSON(null as any)).toThrow(TypeError);
+    expect(() => Phasor.fromJSON(42 as any)).toThrow(TypeError);
+    expect(() => Phasor.fromJSON({ mag: 1 } as any)).toThrow(TypeError);
+    expect(() => Phasor.fromJSON({ tan: 1 } as any)).toThrow(TypeError);
+    expect(() => Phasor.fromJSON({ mag: true, tan: 0 } as any)).toThrow(TypeError);
+    expect(() => JSON.parse('{"mag":1,"tan":null}', Phasor.reviver)).toThrow(TypeError);
+  });
+
+  it('should reject strings other than non-finite numbers', () => {
+    expect(() => Phasor.fromJSON({ mag: 'Inf', tan: 0 } as any)).toThrow(SyntaxError);
+    expect(() => Phasor.fromJSON({ mag: 1, tan: '1' } as any)).toThrow(SyntaxError);
+  });
 });
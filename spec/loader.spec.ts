@@ -0,0 +1,31 @@
+import { isSimd } from '../';
+import { supportsSimd } from '../loader/simd';
+
+describe('Loader', () => {
+  it('should detect SIMD128 support', () => {
+    expect(typeof supportsSimd()).toBe('boolean');
+  });
+
+  it('should load the build selected', () => {
+    const variant = process.env.PHASOR_WASM ?? (supportsSimd() ? 'simd' : 'scalar');
+    expect(isSimd()).toBe(variant === 'simd');
+  });
+
+  it('should fall back to the scalar build', () => {
+    const { PHASOR_WASM } = process.env;
+    const validate = jest.spyOn(WebAssembly, 'validate').mockReturnValue(false);
+    delete process.env.PHASOR_WASM;
+
+    try {
+      jest.isolateModules(() => {
+        expect(require('../loader/simd').supportsSimd()).toBe(false);
+        expect(require('../').isSimd()).toBe(false);
+      });
+    } finally {
+      validate.mockRestore();
+      if (PHASOR_WASM !== undefined) {
+        process.env.PHASOR_WASM = PHASOR_WASM;
+      }
+    }
+  });
+});
@@ -0,0 +1,35 @@
+import { polar, rect } from '../';
+
+const MATH = '<math xmlns="http://www.w3.org/1998/Math/MathML">';
+
+describe('Phasor', () => {
+  it('should be renderable to LaTeX', () => {
+    const p = rect(3, 4);
+    expect(p.toLatex({ unit: 'deg', precision: 1 })).toEqual('5.0\\angle 53.1^\\circ');
+    expect(p.toLatex({ notation: 'rect' })).toEqual('3+4j');
+    expect(p.toLatex({ notation: 'exp', precision: 2 })).toEqual('5.00e^{j0.93}');
+    expect(p.conj().toLatex({ notation: 'exp', precision: 2 })).toEqual('5.00e^{-j0.93}');
+    expect(p.toLatex({ notation: 'exp', unit: 'deg', precision: 2 })).toEqual('5.00e^{j0.93}');
+  });
+
+  it('should render non-finite values to LaTeX', () => {
+    expect(polar(Infinity).toLatex()).toEqual('\\infty\\angle 0');
+    expect(rect(-Infinity).toLatex({ notation: 'rect' })).toEqual('-\\infty+0j');
+    expect(rect(NaN).toLatex()).toEqual('\\mathrm{NaN}');
+  });
+
+  it('should be renderable to MathML', () => {
+    const p = rect(3, -4);
+    expect(p.toMathML({ notation: 'rect' })).toEqual(
+      `${MATH}<mn>3</mn><mo>-</mo><mn>4</mn><mi>j</mi></math>`
+    );
+    expect(p.toMathML({ notation: 'exp', precision: 2 })).toEqual(
+      `${MATH}<mn>5.00</mn><msup><mi>e</mi><mrow><mo>-</mo><mi>j</mi><mn>0.93</mn></mrow></msup></math>`
+    );
+  });
+
+  it('should render non-finite values to MathML', () => {
+    expect(polar(Infinity).toMathML()).toEqual(`${MATH}<mi>∞</mi><mo>∠</mo><mn>0</mn></math>`);
+    expect(rect(NaN).toMathML()).toEqual(`${MATH}<mi>NaN</mi></math>`);
+  });
+});
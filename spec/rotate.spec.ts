@@ -0,0 +1,32 @@
+import { i, polar } from '../';
+import { samples } from './util';
+
+describe('Phasor', () => {
+  it('should be rotatable', () => {
+    samples.forEach(({ mag, ang }) => {
+      expect(polar(mag, ang).rotate(i(2))).toBeCloseTo(polar(mag, ang + Math.PI / 2), 8);
+    });
+  });
+
+  it('should have a unit direction', () => {
+    samples.forEach(({ mag, ang }) => {
+      const u = polar(mag, ang).unit();
+      expect(u.norm()).toBe(1);
+      expect(u.withNorm(mag)).toBeCloseTo(polar(mag, ang));
+    });
+  });
+
+  it('should take the angle of another phasor', () => {
+    samples.forEach(({ mag, ang }) => {
+      expect(polar(mag).withAngleOf(polar(2, ang))).toBeCloseTo(polar(mag, ang));
+    });
+  });
+
+  it('should have an angle between phasors', () => {
+    samples.forEach(({ ang: a }) => {
+      samples.forEach(({ ang: b }) => {
+        expect(polar(1, a).angleBetween(polar(1, b))).toBeCloseTo(polar(1, b - a), 8);
+      });
+    });
+  });
+});
@@ -0,0 +1,31 @@
+import { polar, rect } from '../';
+import { samples } from './util';
+
+describe('Phasor', () => {
+  it('should be comparable by polar tolerance', () => {
+    samples.forEach(({ mag, ang }) => {
+      const p = polar(mag, ang);
+
+      // Scaling zero leaves it as is, and zero has no angle to compare.
+      expect(p.polarEq(polar(mag * (1 + 1E-4), ang), 1E-3, 1E-3)).toBe(true);
+      expect(p.polarEq(polar(mag * (1 + 1E-2), ang), 1E-3, 1E-3)).toBe(mag === 0);
+      expect(p.polarEq(polar(mag, ang + 1E-4), 1E-3, 1E-3)).toBe(true);
+      expect(p.polarEq(polar(mag, ang + 1E-2), 1E-3, 1E-3)).toBe(mag === 0);
+    });
+  });
+
+  it('should have Euclidean distance', () => {
+    samples.forEach(({ re: a, im: b }) => {
+      samples.forEach(({ re: c, im: d }) => {
+        expect(rect(a, b).absDiff(rect(c, d))).toBeCloseTo(Math.hypot(a - c, b - d));
+      });
+    });
+  });
+
+  it('should be comparable by Euclidean distance', () => {
+    samples.forEach(({ re: a, im: b }) => {
+      expect(rect(a, b).euclideanEq(rect(a + 0.3, b + 0.4), 0.51)).toBe(true);
+      expect(rect(a, b).euclideanEq(rect(a + 0.3, b + 0.4), 0.49)).toBe(false);
+    });
+  });
+});
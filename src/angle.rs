@@ -0,0 +1,276 @@
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+use std::ops::{Add, Neg, Sub};
+
+/// An angle represented as a whole number of quarter turns plus a remainder in radians.
+///
+/// Angles constructed from degrees, turns or gradians are reduced exactly, so that right angles
+/// such as `Angle::degrees(90f64)` carry no rounding error at all. Angles constructed from
+/// radians are kept as is, since no multiple of `π/2` other than zero is representable anyway.
+/// For the same reason, equality compares representations rather than values, so that
+/// `Angle::degrees(90f64)` differs from `Angle::radians(FRAC_PI_2)`.
+///
+/// This type is Rust-only; the bindings for other languages take angles in radians.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Angle {
+    quarters: u8,
+    remainder: f64,
+}
+
+impl Angle {
+    /// Splits `x` into a multiple of `quarter` and a remainder no larger than half of it.
+    ///
+    /// Both the reduction modulo a full turn and the subtraction of the nearest multiple of a
+    /// quarter turn are exact in floating point.
+    fn reduce(x: f64, quarter: f64) -> (u8, f64) {
+        let x = x % (4f64 * quarter);
+        let k = (x / quarter).round();
+        ((k as i64).rem_euclid(4) as u8, x - k * quarter)
+    }
+
+    pub fn radians(x: f64) -> Self {
+        Angle {
+            quarters: 0,
+            remainder: x,
+        }
+    }
+
+    pub fn degrees(x: f64) -> Self {
+        let (quarters, r) = Self::reduce(x, 90f64);
+        Angle {
+            quarters,
+            remainder: r.to_radians(),
+        }
+    }
+
+    pub fn turns(x: f64) -> Self {
+        let (quarters, r) = Self::reduce(x, 0.25);
+        Angle {
+            quarters,
+            remainder: r * TAU,
+        }
+    }
+
+    pub fn gradians(x: f64) -> Self {
+        let (quarters, r) = Self::reduce(x, 100f64);
+        Angle {
+            quarters,
+            remainder: r * (PI / 200f64),
+        }
+    }
+
+    /// The number of whole quarter turns, between 0 and 3.
+    pub(crate) fn quarters(&self) -> u8 {
+        self.quarters
+    }
+
+    /// The remainder in radians on top of the whole quarter turns.
+    pub(crate) fn remainder(&self) -> f64 {
+        self.remainder
+    }
+
+    /// The angle in radians, wrapped to `(-π, π]` if constructed from other units.
+    pub fn to_radians(&self) -> f64 {
+        self.in_units(FRAC_PI_2, |r| r)
+    }
+
+    /// The angle in degrees, wrapped to `(-180, 180]` if constructed from other units.
+    pub fn to_degrees(&self) -> f64 {
+        self.in_units(90f64, f64::to_degrees)
+    }
+
+    /// The angle in turns, wrapped to `(-0.5, 0.5]` if constructed from other units.
+    pub fn to_turns(&self) -> f64 {
+        self.in_units(0.25, |r| r / TAU)
+    }
+
+    /// The angle in gradians, wrapped to `(-200, 200]` if constructed from other units.
+    pub fn to_gradians(&self) -> f64 {
+        self.in_units(100f64, |r| r * (200f64 / PI))
+    }
+
+    fn in_units(&self, quarter: f64, convert: impl Fn(f64) -> f64) -> f64 {
+        let r = convert(self.remainder);
+
+        match self.quarters {
+            0 => r,
+            1 => quarter + r,
+            2 if r > 0f64 => r - 2f64 * quarter,
+            2 => 2f64 * quarter + r,
+            _ => r - quarter,
+        }
+    }
+}
+
+impl From<f64> for Angle {
+    fn from(radians: f64) -> Self {
+        Angle::radians(radians)
+    }
+}
+
+impl From<Angle> for f64 {
+    fn from(angle: Angle) -> Self {
+        angle.to_radians()
+    }
+}
+
+impl Neg for Angle {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Angle {
+            quarters: (4 - self.quarters) % 4,
+            remainder: -self.remainder,
+        }
+    }
+}
+
+impl Add for Angle {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        // Carries whole quarter turns out of the remainder, which is exact if both were reduced.
+        let (carry, remainder) = match self.remainder + rhs.remainder {
+            r if r.abs() > FRAC_PI_2 / 2f64 => Self::reduce(r, FRAC_PI_2),
+            r => (0, r),
+        };
+
+        Angle {
+            quarters: (self.quarters + rhs.quarters + carry) % 4,
+            remainder,
+        }
+    }
+}
+
+impl Sub for Angle {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arbitrary::*;
+    use approx::assert_ulps_eq;
+    use test_strategy::proptest;
+
+    #[test]
+    fn right_angles_are_exact() {
+        for (k, d) in [0f64, 90f64, 180f64, 270f64].into_iter().enumerate() {
+            let a = Angle::degrees(d);
+            assert_eq!(a.quarters(), k as u8);
+            assert_eq!(a.remainder(), 0f64);
+            assert_eq!(Angle::turns(k as f64 / 4f64), a);
+            assert_eq!(Angle::gradians(k as f64 * 100f64), a);
+        }
+    }
+
+    #[proptest]
+    fn wraps_around(#[strategy(-1E6f64..1E6)] d: f64, #[strategy(-100i32..100)] n: i32) {
+        let a = Angle::degrees(d);
+        let b = Angle::degrees(d + 360f64 * n as f64);
+
+        assert_eq!(a.quarters(), b.quarters());
+        assert_ulps_eq!(a.remainder(), b.remainder(), epsilon = 1E-9);
+    }
+
+    #[proptest]
+    fn remainder_is_no_larger_than_half_a_quarter_turn(
+        #[strategy(finite())] x: f64,
+        #[strategy(finite())] y: f64,
+    ) {
+        let angles = [Angle::degrees, Angle::turns, Angle::gradians];
+
+        for a in angles {
+            assert!(a(x).remainder().abs() <= FRAC_PI_2 / 2f64);
+
+            for b in angles {
+                assert!((a(x) + b(y)).remainder().abs() <= FRAC_PI_2 / 2f64);
+                assert!((a(x) - b(y)).remainder().abs() <= FRAC_PI_2 / 2f64);
+            }
+        }
+    }
+
+    #[proptest]
+    fn units_are_consistent(#[strategy(-1E3f64..1E3)] t: f64) {
+        let a = Angle::turns(t);
+
+        assert_ulps_eq!(
+            a.to_radians(),
+            Angle::degrees(t * 360f64).to_radians(),
+            epsilon = 1E-12
+        );
+        assert_ulps_eq!(
+            a.to_degrees(),
+            Angle::gradians(t * 400f64).to_degrees(),
+            epsilon = 1E-10
+        );
+    }
+
+    #[proptest]
+    fn degrees_round_trip(#[strategy(-179f64..180f64)] d: f64) {
+        assert_ulps_eq!(Angle::degrees(d).to_degrees(), d, epsilon = 1E-12);
+    }
+
+    #[proptest]
+    fn turns_round_trip(#[strategy(-0.49f64..0.5)] t: f64) {
+        assert_ulps_eq!(Angle::turns(t).to_turns(), t, epsilon = 1E-15);
+    }
+
+    #[proptest]
+    fn gradians_round_trip(#[strategy(-199f64..200f64)] g: f64) {
+        assert_ulps_eq!(Angle::gradians(g).to_gradians(), g, epsilon = 1E-12);
+    }
+
+    #[proptest]
+    fn radians_are_kept_as_is(#[strategy(any())] x: f64) {
+        assert_eq!(Angle::radians(x).to_radians().to_bits(), x.to_bits());
+        assert_eq!(f64::from(Angle::from(x)).to_bits(), x.to_bits());
+    }
+
+    #[test]
+    fn straight_angle_is_positive() {
+        assert_eq!(Angle::degrees(-180f64).to_degrees(), 180f64);
+        assert_eq!(Angle::degrees(180f64).to_degrees(), 180f64);
+    }
+
+    #[proptest]
+    fn addition_wraps_around(
+        #[strategy(-720f64..720f64)] a: f64,
+        #[strategy(-720f64..720f64)] b: f64,
+    ) {
+        let sum = Angle::degrees(a) + Angle::degrees(b);
+        let expected = Angle::degrees(a + b);
+
+        assert_ulps_eq!(sum.to_turns(), expected.to_turns(), epsilon = 1E-12);
+        assert!(sum.to_radians() > -PI && sum.to_radians() <= PI);
+    }
+
+    #[test]
+    fn addition_carries_whole_quarter_turns() {
+        let sum = Angle::degrees(-45f64) + Angle::degrees(-45f64);
+
+        assert_eq!(sum, Angle::degrees(-90f64));
+        assert_eq!(sum.to_radians(), -FRAC_PI_2);
+    }
+
+    #[proptest]
+    fn subtraction_is_addition_of_negation(
+        #[strategy(-720f64..720f64)] a: f64,
+        #[strategy(-720f64..720f64)] b: f64,
+    ) {
+        let (a, b) = (Angle::degrees(a), Angle::degrees(b));
+        assert_eq!(a - b, a + -b);
+        assert_eq!(a - a, Angle::default());
+    }
+
+    #[proptest]
+    fn is_nan_if_not_finite(#[strategy(not_nan())] x: f64) {
+        if !x.is_finite() {
+            assert!(Angle::degrees(x).to_degrees().is_nan());
+            assert!(Angle::turns(x).to_turns().is_nan());
+        }
+    }
+}
@@ -1,4 +1,20 @@
+use crate::Phasor;
+use proptest::arbitrary::{any as arbitrary, Arbitrary};
 use proptest::num::f64::*;
+use proptest::strategy::{Map, Strategy};
+
+fn phasor((mag, tan): (f64, f64)) -> Phasor {
+    Phasor { mag, tan }
+}
+
+impl Arbitrary for Phasor {
+    type Parameters = ();
+    type Strategy = Map<(Any, Any), fn((f64, f64)) -> Phasor>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (arbitrary::<f64>(), arbitrary::<f64>()).prop_map(phasor as fn(_) -> _)
+    }
+}
 
 pub(crate) fn any() -> Any {
     ANY
@@ -0,0 +1,559 @@
+//! Operations over many phasors at once, stored in flat buffers of `f64` pairs.
+//!
+//! Results are written back to the buffer on the left-hand side, which makes these functions
+//! well suited for crossing the wasm boundary, where they are exported as `addBatch`,
+//! `expBatch` and so on, taking `Float64Array`s.
+
+use crate::{reduce, simd, Phasor};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, Div, Mul, Sub};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen::prelude::*;
+
+/// How each phasor is laid out as a pair of `f64` in a buffer.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Layout {
+    /// The magnitude followed by the tangent, as in [`Phasor`] itself.
+    #[default]
+    Phasor,
+
+    /// The real part followed by the imaginary part.
+    Rect,
+}
+
+impl Layout {
+    pub(crate) fn load(self, pair: &[f64]) -> Phasor {
+        match self {
+            Layout::Phasor => Phasor {
+                mag: pair[0],
+                tan: pair[1],
+            },
+            Layout::Rect => Phasor::rect(pair[0], pair[1]),
+        }
+    }
+
+    fn store(self, p: Phasor, pair: &mut [f64]) {
+        let (x, y) = match self {
+            Layout::Phasor => (p.mag, p.tan),
+            Layout::Rect => p.to_rect(),
+        };
+
+        pair[0] = x;
+        pair[1] = y;
+    }
+}
+
+/// The reason a batch operation was rejected.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BatchError {
+    /// A buffer of the given length doesn't hold a whole number of pairs.
+    OddLength(usize),
+
+    /// The buffers hold different numbers of pairs, neither of which is 1.
+    LengthMismatch(usize, usize),
+}
+
+impl Display for BatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            BatchError::OddLength(n) => write!(f, "buffer of length {n} is not made of pairs"),
+            BatchError::LengthMismatch(l, r) => {
+                write!(
+                    f,
+                    "buffers of lengths {l} and {r} hold different numbers of pairs"
+                )
+            }
+        }
+    }
+}
+
+impl Error for BatchError {}
+
+pub(crate) fn pairs(buf: &[f64]) -> Result<usize, BatchError> {
+    if buf.len() % 2 == 0 {
+        Ok(buf.len() / 2)
+    } else {
+        Err(BatchError::OddLength(buf.len()))
+    }
+}
+
+/// Checks that `rhs` holds either as many phasors as `lhs` or a single one, in which case it's
+/// broadcast, as indicated by the result.
+pub(crate) fn broadcast(lhs: &[f64], rhs: &[f64]) -> Result<bool, BatchError> {
+    match (pairs(lhs)?, pairs(rhs)?) {
+        (_, 1) => Ok(true),
+        (l, r) if l == r => Ok(false),
+        _ => Err(BatchError::LengthMismatch(lhs.len(), rhs.len())),
+    }
+}
+
+/// Applies `f` to every phasor in `buf`.
+pub fn map(
+    buf: &mut [f64],
+    layout: Layout,
+    f: impl Fn(Phasor) -> Phasor,
+) -> Result<(), BatchError> {
+    pairs(buf)?;
+
+    for pair in buf.chunks_exact_mut(2) {
+        layout.store(f(layout.load(pair)), pair);
+    }
+
+    Ok(())
+}
+
+/// Applies `f` to every phasor in `lhs` and its counterpart in `rhs`, or the only phasor in
+/// `rhs` if it holds a single one.
+pub fn zip(
+    lhs: &mut [f64],
+    rhs: &[f64],
+    layout: Layout,
+    f: impl Fn(Phasor, Phasor) -> Phasor,
+) -> Result<(), BatchError> {
+    if broadcast(lhs, rhs)? {
+        let q = layout.load(rhs);
+        map(lhs, layout, |p| f(p, q))
+    } else {
+        for (pair, q) in lhs.chunks_exact_mut(2).zip(rhs.chunks_exact(2)) {
+            layout.store(f(layout.load(pair), layout.load(q)), pair);
+        }
+
+        Ok(())
+    }
+}
+
+/// Converts every phasor in `buf` from one layout to another.
+pub fn convert(buf: &mut [f64], from: Layout, to: Layout) -> Result<(), BatchError> {
+    pairs(buf)?;
+
+    for pair in buf.chunks_exact_mut(2) {
+        to.store(from.load(pair), pair);
+    }
+
+    Ok(())
+}
+
+/// Collects `f` of every phasor in `buf`.
+fn collect(buf: &[f64], layout: Layout, f: impl Fn(Phasor) -> f64) -> Result<Vec<f64>, BatchError> {
+    pairs(buf)?;
+    Ok(buf
+        .chunks_exact(2)
+        .map(|pair| f(layout.load(pair)))
+        .collect())
+}
+
+/// The norm of every phasor in `buf`.
+pub fn norm(buf: &[f64], layout: Layout) -> Result<Vec<f64>, BatchError> {
+    collect(buf, layout, |p| p.norm())
+}
+
+/// The angle of every phasor in `buf`.
+pub fn angle(buf: &[f64], layout: Layout) -> Result<Vec<f64>, BatchError> {
+    collect(buf, layout, |p| p.angle())
+}
+
+/// The sum of every phasor in `buf`.
+///
+/// Phasors are added in a fixed order, which the parallel `par::sum` follows as well.
+pub fn sum(buf: &[f64], layout: Layout) -> Result<Phasor, BatchError> {
+    let n = pairs(buf)?;
+    Ok(reduce::sum(n, |i| layout.load(&buf[2 * i..])))
+}
+
+/// The sum of the products of every phasor in `lhs` and its counterpart in `rhs`, or the only
+/// phasor in `rhs` if it holds a single one, without complex conjugation.
+///
+/// Products are added in a fixed order, which the parallel `par::dot` follows as well.
+pub fn dot(lhs: &[f64], rhs: &[f64], layout: Layout) -> Result<Phasor, BatchError> {
+    let n = pairs(lhs)?;
+    let step = if broadcast(lhs, rhs)? { 0 } else { 2 };
+    Ok(reduce::sum(n, |i| {
+        layout.load(&lhs[2 * i..]) * layout.load(&rhs[step * i..])
+    }))
+}
+
+/// The number of phasors deinterleaved at a time by [`vectorized`].
+const CHUNK: usize = 64;
+
+/// Equivalent to [`zip`] for [`Layout::Phasor`], but runs chunks of phasors, deinterleaved into
+/// separate arrays of magnitudes and tangents, through a vectorized [`simd::Kernel`].
+fn vectorized(lhs: &mut [f64], rhs: &[f64], kernel: simd::Kernel) -> Result<(), BatchError> {
+    let broadcast = broadcast(lhs, rhs)?;
+
+    let (mut mag, mut tan) = ([0f64; CHUNK], [0f64; CHUNK]);
+    let (mut rmag, mut rtan) = ([0f64; CHUNK], [0f64; CHUNK]);
+
+    if broadcast {
+        rmag.fill(rhs[0]);
+        rtan.fill(rhs[1]);
+    }
+
+    for (k, chunk) in lhs.chunks_mut(2 * CHUNK).enumerate() {
+        let n = chunk.len() / 2;
+
+        for (i, pair) in chunk.chunks_exact(2).enumerate() {
+            (mag[i], tan[i]) = (pair[0], pair[1]);
+        }
+
+        if !broadcast {
+            for (i, pair) in rhs[2 * CHUNK * k..][..2 * n].chunks_exact(2).enumerate() {
+                (rmag[i], rtan[i]) = (pair[0], pair[1]);
+            }
+        }
+
+        kernel((&mut mag[..n], &mut tan[..n]), (&rmag[..n], &rtan[..n]));
+
+        for (i, pair) in chunk.chunks_exact_mut(2).enumerate() {
+            (pair[0], pair[1]) = (mag[i], tan[i]);
+        }
+    }
+
+    Ok(())
+}
+
+macro_rules! binary {
+    ($(#[$attr:meta])* $name:ident, $op:path $(, $kernel:path)?) => {
+        $(#[$attr])*
+        pub fn $name(lhs: &mut [f64], rhs: &[f64], layout: Layout) -> Result<(), BatchError> {
+            $(
+                if layout == Layout::Phasor {
+                    return vectorized(lhs, rhs, $kernel);
+                }
+            )?
+
+            zip(lhs, rhs, layout, $op)
+        }
+    };
+}
+
+macro_rules! unary {
+    ($(#[$attr:meta])* $name:ident, $op:path) => {
+        $(#[$attr])*
+        pub fn $name(buf: &mut [f64], layout: Layout) -> Result<(), BatchError> {
+            map(buf, layout, $op)
+        }
+    };
+}
+
+binary!(
+    /// Adds every phasor in `rhs` to its counterpart in `lhs`.
+    add,
+    Add::add,
+    simd::add
+);
+
+binary!(
+    /// Subtracts every phasor in `rhs` from its counterpart in `lhs`.
+    sub,
+    Sub::sub
+);
+
+binary!(
+    /// Multiplies every phasor in `lhs` by its counterpart in `rhs`.
+    mul,
+    Mul::mul,
+    simd::mul
+);
+
+binary!(
+    /// Divides every phasor in `lhs` by its counterpart in `rhs`.
+    div,
+    Div::div,
+    simd::div
+);
+
+unary!(neg, std::ops::Neg::neg);
+unary!(conj, Phasor::conj);
+unary!(recip, Phasor::recip);
+unary!(exp, Phasor::exp);
+unary!(ln, Phasor::ln);
+unary!(sqrt, Phasor::sqrt);
+unary!(sinh, Phasor::sinh);
+unary!(cosh, Phasor::cosh);
+
+/// The logarithm of every phasor in `buf` to the given `base`.
+pub fn log(buf: &mut [f64], base: f64, layout: Layout) -> Result<(), BatchError> {
+    map(buf, layout, |p| p.log(base))
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[wasm_bindgen(typescript_custom_section)]
+const BATCH_LAYOUT: &str = r#"
+export type BatchLayout = "phasor" | "rect";
+"#;
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "BatchLayout")]
+    pub type BatchLayout;
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+impl TryFrom<Option<BatchLayout>> for Layout {
+    type Error = JsError;
+
+    fn try_from(layout: Option<BatchLayout>) -> Result<Self, Self::Error> {
+        match layout.and_then(|l| JsValue::from(l).as_string()).as_deref() {
+            None | Some("phasor") => Ok(Layout::Phasor),
+            Some("rect") => Ok(Layout::Rect),
+            Some(l) => Err(JsError::new(&format!("invalid layout `{l}`"))),
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+mod js {
+    use super::*;
+
+    macro_rules! export_binary {
+        ($name:ident, $js:literal) => {
+            #[wasm_bindgen(js_name = $js)]
+            pub fn $name(
+                lhs: &mut [f64],
+                rhs: &[f64],
+                layout: Option<BatchLayout>,
+            ) -> Result<(), JsError> {
+                Ok(super::$name(lhs, rhs, layout.try_into()?)?)
+            }
+        };
+    }
+
+    macro_rules! export_unary {
+        ($name:ident, $js:literal) => {
+            #[wasm_bindgen(js_name = $js)]
+            pub fn $name(buf: &mut [f64], layout: Option<BatchLayout>) -> Result<(), JsError> {
+                Ok(super::$name(buf, layout.try_into()?)?)
+            }
+        };
+    }
+
+    export_binary!(add, "addBatch");
+    export_binary!(sub, "subBatch");
+    export_binary!(mul, "mulBatch");
+    export_binary!(div, "divBatch");
+
+    export_unary!(neg, "negBatch");
+    export_unary!(conj, "conjBatch");
+    export_unary!(recip, "recipBatch");
+    export_unary!(exp, "expBatch");
+    export_unary!(ln, "lnBatch");
+    export_unary!(sqrt, "sqrtBatch");
+    export_unary!(sinh, "sinhBatch");
+    export_unary!(cosh, "coshBatch");
+
+    #[wasm_bindgen(js_name = "logBatch")]
+    pub fn log(buf: &mut [f64], base: f64, layout: Option<BatchLayout>) -> Result<(), JsError> {
+        Ok(super::log(buf, base, layout.try_into()?)?)
+    }
+
+    #[wasm_bindgen(js_name = "normBatch")]
+    pub fn norm(buf: &[f64], layout: Option<BatchLayout>) -> Result<Vec<f64>, JsError> {
+        Ok(super::norm(buf, layout.try_into()?)?)
+    }
+
+    #[wasm_bindgen(js_name = "angleBatch")]
+    pub fn angle(buf: &[f64], layout: Option<BatchLayout>) -> Result<Vec<f64>, JsError> {
+        Ok(super::angle(buf, layout.try_into()?)?)
+    }
+
+    #[wasm_bindgen(js_name = "rectToPhasorBatch")]
+    pub fn rect_to_phasor(buf: &mut [f64]) -> Result<(), JsError> {
+        Ok(super::convert(buf, Layout::Rect, Layout::Phasor)?)
+    }
+
+    #[wasm_bindgen(js_name = "phasorToRectBatch")]
+    pub fn phasor_to_rect(buf: &mut [f64]) -> Result<(), JsError> {
+        Ok(super::convert(buf, Layout::Phasor, Layout::Rect)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arbitrary::{any, *};
+    use crate::strategy;
+    use crate::test_util::{bits, flatten};
+    use proptest::collection::vec;
+    use test_strategy::proptest;
+
+    type Binary = fn(&mut [f64], &[f64], Layout) -> Result<(), BatchError>;
+    type Unary = fn(&mut [f64], Layout) -> Result<(), BatchError>;
+    type BinaryOp = fn(Phasor, Phasor) -> Phasor;
+    type UnaryOp = fn(Phasor) -> Phasor;
+
+    #[proptest]
+    fn binary_ops_match_scalar_ops(
+        #[strategy(vec(strategy::any(), 0..200))] ps: Vec<Phasor>,
+        #[strategy(vec(strategy::any(), #ps.len()))] qs: Vec<Phasor>,
+    ) {
+        let rhs = flatten(&qs);
+
+        let ops: [(Binary, BinaryOp); 4] = [
+            (add, Add::add),
+            (sub, Sub::sub),
+            (mul, Mul::mul),
+            (div, Div::div),
+        ];
+
+        for (batch, scalar) in ops {
+            let mut lhs = flatten(&ps);
+            assert_eq!(batch(&mut lhs, &rhs, Layout::Phasor), Ok(()));
+
+            let expected: Vec<_> = ps.iter().zip(&qs).map(|(&p, &q)| scalar(p, q)).collect();
+            assert_eq!(bits(&lhs), bits(flatten(&expected)));
+        }
+    }
+
+    #[proptest]
+    fn unary_ops_match_scalar_ops(#[strategy(vec(strategy::any(), 0..16))] ps: Vec<Phasor>) {
+        let ops: [(Unary, UnaryOp); 8] = [
+            (neg, std::ops::Neg::neg),
+            (conj, Phasor::conj),
+            (recip, Phasor::recip),
+            (exp, Phasor::exp),
+            (ln, Phasor::ln),
+            (sqrt, Phasor::sqrt),
+            (sinh, Phasor::sinh),
+            (cosh, Phasor::cosh),
+        ];
+
+        for (batch, scalar) in ops {
+            let mut buf = flatten(&ps);
+            assert_eq!(batch(&mut buf, Layout::Phasor), Ok(()));
+
+            let expected: Vec<_> = ps.iter().map(|&p| scalar(p)).collect();
+            assert_eq!(bits(&buf), bits(flatten(&expected)));
+        }
+    }
+
+    #[proptest]
+    fn log_matches_scalar_op(
+        #[strategy(vec(strategy::any(), 0..16))] ps: Vec<Phasor>,
+        #[strategy(any())] base: f64,
+    ) {
+        let mut buf = flatten(&ps);
+        assert_eq!(log(&mut buf, base, Layout::Phasor), Ok(()));
+
+        let expected: Vec<_> = ps.iter().map(|&p| p.log(base)).collect();
+        assert_eq!(bits(&buf), bits(flatten(&expected)));
+    }
+
+    #[proptest]
+    fn single_phasor_is_broadcast(
+        #[strategy(vec(strategy::any(), 0..200))] ps: Vec<Phasor>,
+        #[strategy(strategy::any())] q: Phasor,
+    ) {
+        let mut lhs = flatten(&ps);
+        assert_eq!(mul(&mut lhs, &[q.mag, q.tan], Layout::Phasor), Ok(()));
+
+        let expected: Vec<_> = ps.iter().map(|&p| p * q).collect();
+        assert_eq!(bits(&lhs), bits(flatten(&expected)));
+    }
+
+    #[proptest(cases = 16)]
+    fn dot_is_sum_of_products(
+        #[strategy(vec(strategy::finite(), 0..3000))] ps: Vec<Phasor>,
+        #[strategy(strategy::finite())] q: Phasor,
+    ) {
+        let buf = flatten(&ps);
+        let mut products = buf.clone();
+        assert_eq!(mul(&mut products, &[q.mag, q.tan], Layout::Phasor), Ok(()));
+
+        let d = dot(&buf, &[q.mag, q.tan], Layout::Phasor).unwrap();
+        let s = sum(&products, Layout::Phasor).unwrap();
+        assert_eq!(d.to_le_bytes(), s.to_le_bytes());
+    }
+
+    #[proptest]
+    fn rect_layout_round_trips_through_phasors(
+        #[strategy(vec((finite(), finite()), 0..16))] zs: Vec<(f64, f64)>,
+    ) {
+        let mut buf: Vec<_> = zs.iter().flat_map(|&(re, im)| [re, im]).collect();
+        let ps: Vec<_> = zs.iter().map(|&(re, im)| Phasor::rect(re, im)).collect();
+
+        assert_eq!(convert(&mut buf, Layout::Rect, Layout::Phasor), Ok(()));
+        assert_eq!(bits(&buf), bits(flatten(&ps)));
+
+        assert_eq!(convert(&mut buf, Layout::Phasor, Layout::Rect), Ok(()));
+        let expected: Vec<_> = ps
+            .iter()
+            .flat_map(|p| <[f64; 2]>::from(p.to_rect()))
+            .collect();
+        assert_eq!(bits(&buf), bits(&expected));
+    }
+
+    #[proptest]
+    fn rect_layout_matches_phasor_layout(
+        #[strategy(vec((finite(), finite()), 0..16))] zs: Vec<(f64, f64)>,
+    ) {
+        let mut rect: Vec<_> = zs.iter().flat_map(|&(re, im)| [re, im]).collect();
+        let mut phasor = rect.clone();
+
+        assert_eq!(convert(&mut phasor, Layout::Rect, Layout::Phasor), Ok(()));
+        assert_eq!(norm(&rect, Layout::Rect), norm(&phasor, Layout::Phasor));
+        assert_eq!(angle(&rect, Layout::Rect), angle(&phasor, Layout::Phasor));
+
+        assert_eq!(exp(&mut rect, Layout::Rect), Ok(()));
+        assert_eq!(exp(&mut phasor, Layout::Phasor), Ok(()));
+        assert_eq!(convert(&mut phasor, Layout::Phasor, Layout::Rect), Ok(()));
+        assert_eq!(bits(&rect), bits(&phasor));
+    }
+
+    #[proptest]
+    fn odd_lengths_are_rejected(#[strategy(vec(any(), 1..16))] mut buf: Vec<f64>) {
+        if buf.len() % 2 == 0 {
+            buf.pop();
+        }
+
+        let n = buf.len();
+        assert_eq!(exp(&mut buf, Layout::Phasor), Err(BatchError::OddLength(n)));
+        assert_eq!(
+            add(&mut [], &buf, Layout::Rect),
+            Err(BatchError::OddLength(n))
+        );
+        assert_eq!(norm(&buf, Layout::Phasor), Err(BatchError::OddLength(n)));
+    }
+
+    #[test]
+    fn mismatched_lengths_are_rejected() {
+        let mut lhs = [1f64; 4];
+        let rhs = [1f64; 6];
+
+        assert_eq!(
+            add(&mut lhs, &rhs, Layout::Phasor),
+            Err(BatchError::LengthMismatch(4, 6))
+        );
+
+        assert_eq!(lhs, [1f64; 4]);
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+mod test {
+    use super::*;
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn batch() {
+        let mut buf = [3f64, 0f64, 4f64, 1f64];
+        js::mul(&mut buf, &[2f64, 0f64], None).unwrap();
+        assert_eq!(buf, [6f64, 0f64, 8f64, 1f64]);
+
+        let rect = JsValue::from("rect").unchecked_into();
+        let mut buf = [1f64, 2f64, 3f64, 4f64];
+        js::add(&mut buf, &[10f64, 20f64], Some(rect)).unwrap();
+        for (x, y) in buf.into_iter().zip([11f64, 22f64, 13f64, 24f64]) {
+            assert!((x - y).abs() < 1E-12);
+        }
+
+        assert!(js::exp(&mut [0f64; 3], None).is_err());
+        assert_eq!(js::norm(&[-5f64, 0f64], None).unwrap(), vec![5f64]);
+    }
+}
@@ -0,0 +1,208 @@
+//! An implementation of the `phasor` world declared in `wit/phasor.wit`, which turns the crate
+//! into a WebAssembly component usable by any component model host, e.g. by building it with
+//!
+//! ```sh
+//! cargo build --release --lib --target wasm32-wasip2 --features component
+//! ```
+
+use crate::{AngleUnit, Format, Notation, PhasorError};
+
+wit_bindgen::generate!({
+    path: "wit",
+    world: "phasor",
+});
+
+use exports::phasor::phasor::operations::Guest;
+use phasor::phasor::types::{self, Category, CheckedError, FormatOptions, Phasor as WitPhasor};
+
+type Phasor = crate::Phasor;
+
+impl From<WitPhasor> for Phasor {
+    fn from(p: WitPhasor) -> Self {
+        Phasor {
+            mag: p.mag,
+            tan: p.tan,
+        }
+    }
+}
+
+impl From<Phasor> for WitPhasor {
+    fn from(p: Phasor) -> Self {
+        WitPhasor {
+            mag: p.mag,
+            tan: p.tan,
+        }
+    }
+}
+
+impl From<PhasorError> for CheckedError {
+    fn from(e: PhasorError) -> Self {
+        match e {
+            PhasorError::NanOperand(_) => CheckedError::NanOperand,
+            PhasorError::Indeterminate(_) => CheckedError::Indeterminate,
+            PhasorError::DivisionByZero(_) => CheckedError::DivisionByZero,
+            PhasorError::Overflow(_) => CheckedError::Overflow,
+        }
+    }
+}
+
+impl From<FormatOptions> for Format {
+    fn from(o: FormatOptions) -> Self {
+        Format {
+            notation: match o.notation {
+                types::Notation::Polar => Notation::Polar,
+                types::Notation::Rect => Notation::Rect,
+                types::Notation::Exp => Notation::Exp,
+            },
+            unit: match o.unit {
+                types::AngleUnit::Radians => AngleUnit::Radians,
+                types::AngleUnit::Degrees => AngleUnit::Degrees,
+            },
+            precision: o.precision.map(|p| p as usize),
+            engineering: o.engineering,
+        }
+    }
+}
+
+fn checked(r: Result<Phasor, PhasorError>) -> Result<WitPhasor, CheckedError> {
+    r.map(Into::into).map_err(Into::into)
+}
+
+struct Component;
+
+impl Guest for Component {
+    fn rect(re: f64, im: f64) -> WitPhasor {
+        Phasor::rect(re, im).into()
+    }
+
+    fn polar(mag: f64, angle: f64) -> WitPhasor {
+        Phasor::polar(mag, angle).into()
+    }
+
+    fn real(p: WitPhasor) -> f64 {
+        Phasor::from(p).real()
+    }
+
+    fn imag(p: WitPhasor) -> f64 {
+        Phasor::from(p).imag()
+    }
+
+    fn norm(p: WitPhasor) -> f64 {
+        Phasor::from(p).norm()
+    }
+
+    fn angle(p: WitPhasor) -> f64 {
+        Phasor::from(p).angle()
+    }
+
+    fn classify(p: WitPhasor) -> Category {
+        use std::num::FpCategory;
+
+        match Phasor::from(p).classify() {
+            FpCategory::Nan => Category::Nan,
+            FpCategory::Infinite => Category::Infinite,
+            FpCategory::Zero => Category::Zero,
+            FpCategory::Subnormal => Category::Subnormal,
+            FpCategory::Normal => Category::Normal,
+        }
+    }
+
+    fn add(lhs: WitPhasor, rhs: WitPhasor) -> WitPhasor {
+        (Phasor::from(lhs) + Phasor::from(rhs)).into()
+    }
+
+    fn sub(lhs: WitPhasor, rhs: WitPhasor) -> WitPhasor {
+        (Phasor::from(lhs) - Phasor::from(rhs)).into()
+    }
+
+    fn mul(lhs: WitPhasor, rhs: WitPhasor) -> WitPhasor {
+        (Phasor::from(lhs) * Phasor::from(rhs)).into()
+    }
+
+    fn div(lhs: WitPhasor, rhs: WitPhasor) -> WitPhasor {
+        (Phasor::from(lhs) / Phasor::from(rhs)).into()
+    }
+
+    fn neg(p: WitPhasor) -> WitPhasor {
+        (-Phasor::from(p)).into()
+    }
+
+    fn conj(p: WitPhasor) -> WitPhasor {
+        Phasor::from(p).conj().into()
+    }
+
+    fn recip(p: WitPhasor) -> WitPhasor {
+        Phasor::from(p).recip().into()
+    }
+
+    fn exp(p: WitPhasor) -> WitPhasor {
+        Phasor::from(p).exp().into()
+    }
+
+    fn ln(p: WitPhasor) -> WitPhasor {
+        Phasor::from(p).ln().into()
+    }
+
+    fn log(p: WitPhasor, base: f64) -> WitPhasor {
+        Phasor::from(p).log(base).into()
+    }
+
+    fn sqrt(p: WitPhasor) -> WitPhasor {
+        Phasor::from(p).sqrt().into()
+    }
+
+    fn sinh(p: WitPhasor) -> WitPhasor {
+        Phasor::from(p).sinh().into()
+    }
+
+    fn cosh(p: WitPhasor) -> WitPhasor {
+        Phasor::from(p).cosh().into()
+    }
+
+    fn checked_add(lhs: WitPhasor, rhs: WitPhasor) -> Result<WitPhasor, CheckedError> {
+        checked(Phasor::from(lhs).checked_add(rhs.into()))
+    }
+
+    fn checked_sub(lhs: WitPhasor, rhs: WitPhasor) -> Result<WitPhasor, CheckedError> {
+        checked(Phasor::from(lhs).checked_sub(rhs.into()))
+    }
+
+    fn checked_mul(lhs: WitPhasor, rhs: WitPhasor) -> Result<WitPhasor, CheckedError> {
+        checked(Phasor::from(lhs).checked_mul(rhs.into()))
+    }
+
+    fn checked_div(lhs: WitPhasor, rhs: WitPhasor) -> Result<WitPhasor, CheckedError> {
+        checked(Phasor::from(lhs).checked_div(rhs.into()))
+    }
+
+    fn checked_recip(p: WitPhasor) -> Result<WitPhasor, CheckedError> {
+        checked(Phasor::from(p).checked_recip())
+    }
+
+    fn checked_exp(p: WitPhasor) -> Result<WitPhasor, CheckedError> {
+        checked(Phasor::from(p).checked_exp())
+    }
+
+    fn checked_ln(p: WitPhasor) -> Result<WitPhasor, CheckedError> {
+        checked(Phasor::from(p).checked_ln())
+    }
+
+    fn checked_log(p: WitPhasor, base: f64) -> Result<WitPhasor, CheckedError> {
+        checked(Phasor::from(p).checked_log(base))
+    }
+
+    fn checked_sinh(p: WitPhasor) -> Result<WitPhasor, CheckedError> {
+        checked(Phasor::from(p).checked_sinh())
+    }
+
+    fn checked_cosh(p: WitPhasor) -> Result<WitPhasor, CheckedError> {
+        checked(Phasor::from(p).checked_cosh())
+    }
+
+    fn format(p: WitPhasor, options: Option<FormatOptions>) -> String {
+        let format = options.map(Format::from).unwrap_or_default();
+        Phasor::from(p).format(format).to_string()
+    }
+}
+
+export!(Component);
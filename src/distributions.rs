@@ -0,0 +1,212 @@
+use crate::Phasor;
+use rand::distributions::Distribution;
+use rand::Rng;
+use std::f64::consts::PI;
+use std::num::FpCategory;
+
+/// Samples phasors uniformly distributed on the unit circle.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct UnitCircle;
+
+impl Distribution<Phasor> for UnitCircle {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Phasor {
+        Phasor::polar(1f64, rng.gen_range(-PI..PI))
+    }
+}
+
+/// Samples phasors uniformly distributed in the unit disk.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct UnitDisk;
+
+impl Distribution<Phasor> for UnitDisk {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Phasor {
+        Phasor::polar(rng.gen::<f64>().sqrt(), rng.gen_range(-PI..PI))
+    }
+}
+
+/// Samples circularly symmetric complex Gaussian noise with zero mean and variance `σ²`,
+/// that is, with real and imaginary parts independently distributed as `N(0, σ²/2)`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ComplexNormal {
+    sigma: f64,
+}
+
+impl ComplexNormal {
+    pub fn new(sigma: f64) -> Option<Self> {
+        if sigma.is_finite() && sigma >= 0f64 {
+            Some(ComplexNormal { sigma })
+        } else {
+            None
+        }
+    }
+}
+
+impl Distribution<Phasor> for ComplexNormal {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Phasor {
+        // The squared norm is exponentially distributed with mean σ².
+        let u = 1f64 - rng.gen::<f64>();
+        Phasor::polar(self.sigma * (-u.ln()).sqrt(), rng.gen_range(-PI..PI))
+    }
+}
+
+/// Samples phasors of a given [`FpCategory`], with both magnitude and tangent spanning their
+/// entire ranges.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Classified(pub FpCategory);
+
+/// Samples phasors of any [`FpCategory`] with equal probability.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Special;
+
+const CATEGORIES: [FpCategory; 5] = [
+    FpCategory::Nan,
+    FpCategory::Infinite,
+    FpCategory::Zero,
+    FpCategory::Subnormal,
+    FpCategory::Normal,
+];
+
+fn float<R: Rng + ?Sized>(rng: &mut R, category: FpCategory) -> f64 {
+    const MANTISSA: u64 = (1 << 52) - 1;
+
+    let sign = rng.gen::<u64>() & (1 << 63);
+    let bits = match category {
+        FpCategory::Nan => 0x7FF << 52 | rng.gen_range(1..=MANTISSA),
+        FpCategory::Infinite => 0x7FF << 52,
+        FpCategory::Zero => 0,
+        FpCategory::Subnormal => rng.gen_range(1..=MANTISSA),
+        FpCategory::Normal => rng.gen_range(1..0x7FF) << 52 | rng.gen::<u64>() & MANTISSA,
+    };
+
+    f64::from_bits(sign | bits)
+}
+
+impl Distribution<Phasor> for Classified {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Phasor {
+        match self.0 {
+            FpCategory::Nan => {
+                let other = CATEGORIES[rng.gen_range(0..CATEGORIES.len())];
+
+                if rng.gen() {
+                    Phasor {
+                        mag: float(rng, FpCategory::Nan),
+                        tan: float(rng, other),
+                    }
+                } else {
+                    Phasor {
+                        mag: float(rng, other),
+                        tan: float(rng, FpCategory::Nan),
+                    }
+                }
+            }
+
+            category => {
+                let other = CATEGORIES[rng.gen_range(1..CATEGORIES.len())];
+
+                Phasor {
+                    mag: float(rng, category),
+                    tan: float(rng, other),
+                }
+            }
+        }
+    }
+}
+
+impl Distribution<Phasor> for Special {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Phasor {
+        Classified(CATEGORIES[rng.gen_range(0..CATEGORIES.len())]).sample(rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::{assert_relative_eq, assert_ulps_eq};
+    use rand::{rngs::SmallRng, SeedableRng};
+    use test_strategy::proptest;
+
+    const SAMPLES: usize = 100_000;
+
+    #[proptest]
+    fn unit_circle_has_unit_norm(seed: u64) {
+        let rng = SmallRng::seed_from_u64(seed);
+        for p in UnitCircle.sample_iter(rng).take(100) {
+            assert_ulps_eq!(p.norm(), 1f64);
+        }
+    }
+
+    #[proptest]
+    fn unit_disk_is_bounded_by_the_unit_circle(seed: u64) {
+        let rng = SmallRng::seed_from_u64(seed);
+        for p in UnitDisk.sample_iter(rng).take(100) {
+            assert!(p.norm() <= 1f64);
+        }
+    }
+
+    #[test]
+    fn unit_circle_has_zero_mean() {
+        let rng = SmallRng::seed_from_u64(0);
+        let (re, im) = UnitCircle
+            .sample_iter(rng)
+            .take(SAMPLES)
+            .fold((0f64, 0f64), |(re, im), p| (re + p.real(), im + p.imag()));
+
+        assert_relative_eq!(re / SAMPLES as f64, 0f64, epsilon = 1E-2);
+        assert_relative_eq!(im / SAMPLES as f64, 0f64, epsilon = 1E-2);
+    }
+
+    #[test]
+    fn unit_disk_has_uniform_density() {
+        let rng = SmallRng::seed_from_u64(0);
+        let inner = UnitDisk
+            .sample_iter(rng)
+            .take(SAMPLES)
+            .filter(|p| p.norm() < 0.5)
+            .count();
+
+        assert_relative_eq!(inner as f64 / SAMPLES as f64, 0.25, epsilon = 1E-2);
+    }
+
+    #[test]
+    fn complex_normal_has_given_variance_split_evenly() {
+        let rng = SmallRng::seed_from_u64(0);
+        let (re, im) = ComplexNormal::new(2f64)
+            .unwrap()
+            .sample_iter(rng)
+            .take(SAMPLES)
+            .fold((0f64, 0f64), |(re, im), p| {
+                (re + p.real().powi(2), im + p.imag().powi(2))
+            });
+
+        assert_relative_eq!(re / SAMPLES as f64, 2f64, max_relative = 2E-2);
+        assert_relative_eq!(im / SAMPLES as f64, 2f64, max_relative = 2E-2);
+    }
+
+    #[test]
+    fn complex_normal_requires_finite_nonnegative_deviation() {
+        assert!(ComplexNormal::new(0f64).is_some());
+        assert!(ComplexNormal::new(-1f64).is_none());
+        assert!(ComplexNormal::new(f64::INFINITY).is_none());
+        assert!(ComplexNormal::new(f64::NAN).is_none());
+    }
+
+    #[proptest]
+    fn classified_samples_the_given_category(seed: u64) {
+        let mut rng = SmallRng::seed_from_u64(seed);
+        for category in CATEGORIES {
+            for _ in 0..100 {
+                assert_eq!(Classified(category).sample(&mut rng).classify(), category);
+            }
+        }
+    }
+
+    #[test]
+    fn special_samples_every_category() {
+        let rng = SmallRng::seed_from_u64(0);
+        let samples: Vec<_> = Special.sample_iter(rng).take(1000).collect();
+
+        for category in CATEGORIES {
+            assert!(samples.iter().any(|p| p.classify() == category));
+        }
+    }
+}
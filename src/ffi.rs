@@ -0,0 +1,481 @@
+//! A stable C ABI, declared in `include/phasor.h`.
+//!
+//! The header is generated by [cbindgen](https://github.com/mozilla/cbindgen) from this module,
+//! so every change here must be followed by
+//!
+//! ```sh
+//! cbindgen --config cbindgen.toml --output include/phasor.h
+//! ```
+//!
+//! The `phasor_checked_*` functions store their result in `out` only if the operation succeeds,
+//! so `out` must be valid for writes. Much like `snprintf`, [`phasor_format`] writes at most `len`
+//! bytes into `buf`, including the terminating NUL and truncating at a character boundary, so
+//! `buf` must be valid for `len` writes unless `len` is zero, and `format` must be either `NULL`
+//! or valid for reads.
+
+#![allow(clippy::missing_safety_doc)]
+
+use crate::{AngleUnit, Format, Notation, Phasor, PhasorError};
+use std::ffi::c_char;
+use std::num::FpCategory;
+use std::ptr;
+
+/// The floating point category of a phasor, see [`Phasor::classify`].
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PhasorCategory {
+    Nan,
+    Infinite,
+    Zero,
+    Subnormal,
+    Normal,
+}
+
+impl From<FpCategory> for PhasorCategory {
+    fn from(c: FpCategory) -> Self {
+        match c {
+            FpCategory::Nan => PhasorCategory::Nan,
+            FpCategory::Infinite => PhasorCategory::Infinite,
+            FpCategory::Zero => PhasorCategory::Zero,
+            FpCategory::Subnormal => PhasorCategory::Subnormal,
+            FpCategory::Normal => PhasorCategory::Normal,
+        }
+    }
+}
+
+/// The outcome of a checked operation, see [`PhasorError`].
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PhasorStatus {
+    /// The operation succeeded.
+    Ok,
+    /// At least one of the operands is _NaN_.
+    NanOperand,
+    /// The operation has no well defined result, e.g. `∞ - ∞`, `0 × ∞` or `0 / 0`.
+    Indeterminate,
+    /// The result is infinite because a finite operand was divided by zero.
+    DivisionByZero,
+    /// The result overflowed to infinity even though all operands are finite.
+    Overflow,
+}
+
+impl From<PhasorError> for PhasorStatus {
+    fn from(e: PhasorError) -> Self {
+        match e {
+            PhasorError::NanOperand(_) => PhasorStatus::NanOperand,
+            PhasorError::Indeterminate(_) => PhasorStatus::Indeterminate,
+            PhasorError::DivisionByZero(_) => PhasorStatus::DivisionByZero,
+            PhasorError::Overflow(_) => PhasorStatus::Overflow,
+        }
+    }
+}
+
+/// The notation used by [`phasor_format`], see [`Notation`].
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PhasorNotation {
+    #[default]
+    Polar,
+    Rect,
+    Exp,
+}
+
+/// The unit of angles written by [`phasor_format`], see [`AngleUnit`].
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PhasorAngleUnit {
+    #[default]
+    Radians,
+    Degrees,
+}
+
+/// Options for [`phasor_format`], see [`Format`].
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PhasorFormat {
+    pub notation: PhasorNotation,
+    pub unit: PhasorAngleUnit,
+    /// The number of decimal places, or the shortest representation that round-trips if negative.
+    pub precision: i32,
+    pub engineering: bool,
+}
+
+impl Default for PhasorFormat {
+    fn default() -> Self {
+        PhasorFormat {
+            notation: PhasorNotation::default(),
+            unit: PhasorAngleUnit::default(),
+            precision: -1,
+            engineering: false,
+        }
+    }
+}
+
+impl From<PhasorFormat> for Format {
+    fn from(f: PhasorFormat) -> Self {
+        Format {
+            notation: match f.notation {
+                PhasorNotation::Polar => Notation::Polar,
+                PhasorNotation::Rect => Notation::Rect,
+                PhasorNotation::Exp => Notation::Exp,
+            },
+            unit: match f.unit {
+                PhasorAngleUnit::Radians => AngleUnit::Radians,
+                PhasorAngleUnit::Degrees => AngleUnit::Degrees,
+            },
+            precision: usize::try_from(f.precision).ok(),
+            engineering: f.engineering,
+        }
+    }
+}
+
+/// Constructs a phasor from its real and imaginary parts.
+#[no_mangle]
+pub extern "C" fn phasor_rect(re: f64, im: f64) -> Phasor {
+    Phasor::rect(re, im)
+}
+
+/// Constructs a phasor from its magnitude and angle in radians.
+#[no_mangle]
+pub extern "C" fn phasor_polar(mag: f64, angle: f64) -> Phasor {
+    Phasor::polar(mag, angle)
+}
+
+/// The real part.
+#[no_mangle]
+pub extern "C" fn phasor_real(p: Phasor) -> f64 {
+    p.real()
+}
+
+/// The imaginary part.
+#[no_mangle]
+pub extern "C" fn phasor_imag(p: Phasor) -> f64 {
+    p.imag()
+}
+
+/// The magnitude.
+#[no_mangle]
+pub extern "C" fn phasor_norm(p: Phasor) -> f64 {
+    p.norm()
+}
+
+/// The angle in radians.
+#[no_mangle]
+pub extern "C" fn phasor_angle(p: Phasor) -> f64 {
+    p.angle()
+}
+
+/// The floating point category.
+#[no_mangle]
+pub extern "C" fn phasor_classify(p: Phasor) -> PhasorCategory {
+    p.classify().into()
+}
+
+/// The sum.
+#[no_mangle]
+pub extern "C" fn phasor_add(lhs: Phasor, rhs: Phasor) -> Phasor {
+    lhs + rhs
+}
+
+/// The difference.
+#[no_mangle]
+pub extern "C" fn phasor_sub(lhs: Phasor, rhs: Phasor) -> Phasor {
+    lhs - rhs
+}
+
+/// The product.
+#[no_mangle]
+pub extern "C" fn phasor_mul(lhs: Phasor, rhs: Phasor) -> Phasor {
+    lhs * rhs
+}
+
+/// The quotient.
+#[no_mangle]
+pub extern "C" fn phasor_div(lhs: Phasor, rhs: Phasor) -> Phasor {
+    lhs / rhs
+}
+
+/// The negation.
+#[no_mangle]
+pub extern "C" fn phasor_neg(p: Phasor) -> Phasor {
+    -p
+}
+
+/// The complex conjugate.
+#[no_mangle]
+pub extern "C" fn phasor_conj(p: Phasor) -> Phasor {
+    p.conj()
+}
+
+/// The reciprocal.
+#[no_mangle]
+pub extern "C" fn phasor_recip(p: Phasor) -> Phasor {
+    p.recip()
+}
+
+/// The exponential.
+#[no_mangle]
+pub extern "C" fn phasor_exp(p: Phasor) -> Phasor {
+    p.exp()
+}
+
+/// The natural logarithm.
+#[no_mangle]
+pub extern "C" fn phasor_ln(p: Phasor) -> Phasor {
+    p.ln()
+}
+
+/// The principal square root.
+#[no_mangle]
+pub extern "C" fn phasor_sqrt(p: Phasor) -> Phasor {
+    p.sqrt()
+}
+
+/// The hyperbolic sine.
+#[no_mangle]
+pub extern "C" fn phasor_sinh(p: Phasor) -> Phasor {
+    p.sinh()
+}
+
+/// The hyperbolic cosine.
+#[no_mangle]
+pub extern "C" fn phasor_cosh(p: Phasor) -> Phasor {
+    p.cosh()
+}
+
+/// The logarithm to an arbitrary base.
+#[no_mangle]
+pub extern "C" fn phasor_log(p: Phasor, base: f64) -> Phasor {
+    p.log(base)
+}
+
+/// Stores `r` in `out` if it is `Ok`.
+unsafe fn check(r: Result<Phasor, PhasorError>, out: *mut Phasor) -> PhasorStatus {
+    match r {
+        Ok(p) => {
+            out.write(p);
+            PhasorStatus::Ok
+        }
+
+        Err(e) => e.into(),
+    }
+}
+
+/// See [`Phasor::checked_add`].
+#[no_mangle]
+pub unsafe extern "C" fn phasor_checked_add(
+    lhs: Phasor,
+    rhs: Phasor,
+    out: *mut Phasor,
+) -> PhasorStatus {
+    check(lhs.checked_add(rhs), out)
+}
+
+/// See [`Phasor::checked_sub`].
+#[no_mangle]
+pub unsafe extern "C" fn phasor_checked_sub(
+    lhs: Phasor,
+    rhs: Phasor,
+    out: *mut Phasor,
+) -> PhasorStatus {
+    check(lhs.checked_sub(rhs), out)
+}
+
+/// See [`Phasor::checked_mul`].
+#[no_mangle]
+pub unsafe extern "C" fn phasor_checked_mul(
+    lhs: Phasor,
+    rhs: Phasor,
+    out: *mut Phasor,
+) -> PhasorStatus {
+    check(lhs.checked_mul(rhs), out)
+}
+
+/// See [`Phasor::checked_div`].
+#[no_mangle]
+pub unsafe extern "C" fn phasor_checked_div(
+    lhs: Phasor,
+    rhs: Phasor,
+    out: *mut Phasor,
+) -> PhasorStatus {
+    check(lhs.checked_div(rhs), out)
+}
+
+/// See [`Phasor::checked_recip`].
+#[no_mangle]
+pub unsafe extern "C" fn phasor_checked_recip(p: Phasor, out: *mut Phasor) -> PhasorStatus {
+    check(p.checked_recip(), out)
+}
+
+/// See [`Phasor::checked_exp`].
+#[no_mangle]
+pub unsafe extern "C" fn phasor_checked_exp(p: Phasor, out: *mut Phasor) -> PhasorStatus {
+    check(p.checked_exp(), out)
+}
+
+/// See [`Phasor::checked_ln`].
+#[no_mangle]
+pub unsafe extern "C" fn phasor_checked_ln(p: Phasor, out: *mut Phasor) -> PhasorStatus {
+    check(p.checked_ln(), out)
+}
+
+/// See [`Phasor::checked_sinh`].
+#[no_mangle]
+pub unsafe extern "C" fn phasor_checked_sinh(p: Phasor, out: *mut Phasor) -> PhasorStatus {
+    check(p.checked_sinh(), out)
+}
+
+/// See [`Phasor::checked_cosh`].
+#[no_mangle]
+pub unsafe extern "C" fn phasor_checked_cosh(p: Phasor, out: *mut Phasor) -> PhasorStatus {
+    check(p.checked_cosh(), out)
+}
+
+/// See [`Phasor::checked_log`].
+#[no_mangle]
+pub unsafe extern "C" fn phasor_checked_log(
+    p: Phasor,
+    base: f64,
+    out: *mut Phasor,
+) -> PhasorStatus {
+    check(p.checked_log(base), out)
+}
+
+/// The default options for [`phasor_format`].
+#[no_mangle]
+pub extern "C" fn phasor_format_default() -> PhasorFormat {
+    PhasorFormat::default()
+}
+
+/// Writes `p` into `buf` following `format`, or the defaults if `NULL`, and returns its length.
+#[no_mangle]
+pub unsafe extern "C" fn phasor_format(
+    p: Phasor,
+    format: *const PhasorFormat,
+    buf: *mut c_char,
+    len: usize,
+) -> usize {
+    let format = format.as_ref().copied().unwrap_or_default();
+    let s = p.format(format.into()).to_string();
+
+    if len > 0 {
+        let mut n = s.len().min(len - 1);
+        while !s.is_char_boundary(n) {
+            n -= 1;
+        }
+
+        ptr::copy_nonoverlapping(s.as_ptr(), buf.cast(), n);
+        buf.add(n).write(0);
+    }
+
+    s.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::any;
+    use std::slice;
+    use test_strategy::proptest;
+
+    /// Formats `p` into a buffer of exactly the size required.
+    fn formatted(p: Phasor, format: *const PhasorFormat) -> String {
+        let n = unsafe { phasor_format(p, format, ptr::null_mut(), 0) };
+        let mut buf = vec![1 as c_char; n + 1];
+        assert_eq!(
+            unsafe { phasor_format(p, format, buf.as_mut_ptr(), buf.len()) },
+            n
+        );
+        read(&buf)
+    }
+
+    /// Reads a NUL-terminated string written by [`phasor_format`].
+    fn read(buf: &[c_char]) -> String {
+        let bytes: &[u8] = unsafe { slice::from_raw_parts(buf.as_ptr().cast(), buf.len()) };
+        let end = bytes.iter().position(|&b| b == 0).unwrap();
+        String::from_utf8(bytes[..end].to_vec()).unwrap()
+    }
+
+    #[proptest]
+    fn binary_operations_match_operators(
+        #[strategy(any())] p: Phasor,
+        #[strategy(any())] q: Phasor,
+    ) {
+        assert_eq!(phasor_add(p, q).to_le_bytes(), (p + q).to_le_bytes());
+        assert_eq!(phasor_sub(p, q).to_le_bytes(), (p - q).to_le_bytes());
+        assert_eq!(phasor_mul(p, q).to_le_bytes(), (p * q).to_le_bytes());
+        assert_eq!(phasor_div(p, q).to_le_bytes(), (p / q).to_le_bytes());
+    }
+
+    #[proptest]
+    fn classify_matches_fp_category(#[strategy(any())] p: Phasor) {
+        assert_eq!(phasor_classify(p), p.classify().into());
+    }
+
+    #[proptest]
+    fn checked_operations_report_status(
+        #[strategy(any())] p: Phasor,
+        #[strategy(any())] q: Phasor,
+    ) {
+        let mut out = Phasor::NAN;
+        let status = unsafe { phasor_checked_div(p, q, &mut out) };
+
+        match p.checked_div(q) {
+            Ok(r) => {
+                assert_eq!(status, PhasorStatus::Ok);
+                assert_eq!(out.to_le_bytes(), r.to_le_bytes());
+            }
+
+            Err(e) => {
+                assert_eq!(status, e.into());
+                assert!(out.is_nan());
+            }
+        }
+    }
+
+    #[proptest]
+    fn format_matches_display(#[strategy(any())] p: Phasor) {
+        assert_eq!(formatted(p, ptr::null()), p.to_string());
+    }
+
+    #[proptest]
+    fn format_honors_options(#[strategy(any())] p: Phasor, #[strategy(0..8i32)] precision: i32) {
+        let format = PhasorFormat {
+            notation: PhasorNotation::Rect,
+            unit: PhasorAngleUnit::Degrees,
+            precision,
+            engineering: true,
+        };
+
+        assert_eq!(formatted(p, &format), p.format(format.into()).to_string());
+    }
+
+    #[proptest]
+    fn format_truncates_at_character_boundaries(
+        #[strategy(any())] p: Phasor,
+        #[strategy(1..32usize)] len: usize,
+    ) {
+        let s = p.to_string();
+        let mut buf = [1 as c_char; 32];
+        let n = unsafe { phasor_format(p, ptr::null(), buf.as_mut_ptr(), len) };
+
+        let r = read(&buf[..len]);
+
+        assert_eq!(n, s.len());
+        assert!(s.starts_with(&r));
+        assert!(r.len() < len && r.len() + 4 >= len.min(s.len() + 1));
+        assert!(buf[len..].iter().all(|&c| c == 1));
+    }
+
+    #[test]
+    fn format_accepts_null_buffer_of_zero_length() {
+        let s = Phasor::ONE.to_string();
+        let n = unsafe { phasor_format(Phasor::ONE, ptr::null(), ptr::null_mut(), 0) };
+        assert_eq!(n, s.len());
+    }
+
+    #[test]
+    fn negative_precision_is_shortest_representation() {
+        assert_eq!(Format::from(phasor_format_default()), Format::default());
+    }
+}
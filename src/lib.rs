@@ -1,14 +1,49 @@
 #![cfg_attr(test, allow(clippy::float_cmp, clippy::eq_op))]
+// `usize::is_multiple_of` needs Rust 1.87, which is more recent than this crate requires.
+#![allow(clippy::manual_is_multiple_of)]
 
+mod angle;
 mod phasor;
+mod reduce;
+mod simd;
 mod trig;
+mod vec;
 
-#[cfg(test)]
+pub mod batch;
+
+#[cfg(all(feature = "component", target_arch = "wasm32"))]
+mod component;
+
+#[cfg(feature = "rand")]
+pub mod distributions;
+
+#[cfg(all(feature = "capi", not(target_arch = "wasm32")))]
+pub mod ffi;
+
+#[cfg(feature = "rayon")]
+pub mod par;
+
+#[cfg(feature = "python")]
+mod python;
+
+#[cfg(any(test, feature = "proptest"))]
+#[cfg_attr(not(test), allow(dead_code))]
 mod arbitrary;
 
-pub use crate::phasor::Phasor;
+#[cfg(any(test, feature = "proptest"))]
+pub mod strategy;
+
+#[cfg(test)]
+mod test_util;
+
+pub use crate::angle::Angle;
+pub use crate::phasor::{
+    AngleUnit, Canonical, Continuation, Finite, Format, Formatted, Mismatch, Multivalued, NotNan,
+    Notation, Operation, Phasor, PhasorError, Phasors, PolarTolerance, UlpsDistance,
+};
+pub use crate::vec::PhasorVec;
 pub use ::approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 #[global_allocator]
 static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
@@ -0,0 +1,194 @@
+//! Parallel variants of the operations in [`batch`], spread across the threads of
+//! the current [`rayon`] thread pool.
+//!
+//! Elementwise operations yield exactly the same results as their sequential counterparts.
+//! So do the reductions [`sum`] and [`dot`], which add terms in a fixed order regardless of the
+//! number of threads, making them reproducible.
+
+use crate::batch::{self, pairs, BatchError, Layout};
+use crate::{reduce, Phasor};
+use rayon::prelude::*;
+
+/// The number of phasors processed sequentially by each parallel task.
+const GRAIN: usize = 4096;
+
+/// Applies `f` to every chunk of `buf` in parallel.
+fn map(
+    buf: &mut [f64],
+    f: impl Fn(&mut [f64]) -> Result<(), BatchError> + Sync + Send,
+) -> Result<(), BatchError> {
+    pairs(buf)?;
+    buf.par_chunks_mut(2 * GRAIN).try_for_each(f)
+}
+
+/// Applies `f` to every chunk of `lhs` and its counterpart in `rhs` in parallel, or all of `rhs`
+/// if it holds a single phasor.
+fn zip(
+    lhs: &mut [f64],
+    rhs: &[f64],
+    f: impl Fn(&mut [f64], &[f64]) -> Result<(), BatchError> + Sync,
+) -> Result<(), BatchError> {
+    if batch::broadcast(lhs, rhs)? {
+        lhs.par_chunks_mut(2 * GRAIN).try_for_each(|l| f(l, rhs))
+    } else {
+        lhs.par_chunks_mut(2 * GRAIN)
+            .zip(rhs.par_chunks(2 * GRAIN))
+            .try_for_each(|(l, r)| f(l, r))
+    }
+}
+
+/// Collects `f` of every chunk of `buf` in parallel.
+fn collect(
+    buf: &[f64],
+    f: impl Fn(&[f64]) -> Result<Vec<f64>, BatchError> + Sync + Send,
+) -> Result<Vec<f64>, BatchError> {
+    pairs(buf)?;
+
+    let chunks: Result<Vec<_>, _> = buf.par_chunks(2 * GRAIN).map(f).collect();
+    Ok(chunks?.concat())
+}
+
+macro_rules! binary {
+    ($($name:ident),*) => {
+        $(
+            #[doc = concat!("Parallel variant of [`batch::", stringify!($name), "`].")]
+            pub fn $name(lhs: &mut [f64], rhs: &[f64], layout: Layout) -> Result<(), BatchError> {
+                zip(lhs, rhs, |l, r| batch::$name(l, r, layout))
+            }
+        )*
+    };
+}
+
+macro_rules! unary {
+    ($($name:ident),*) => {
+        $(
+            #[doc = concat!("Parallel variant of [`batch::", stringify!($name), "`].")]
+            pub fn $name(buf: &mut [f64], layout: Layout) -> Result<(), BatchError> {
+                map(buf, |b| batch::$name(b, layout))
+            }
+        )*
+    };
+}
+
+binary!(add, sub, mul, div);
+unary!(neg, conj, recip, exp, ln, sqrt, sinh, cosh);
+
+/// Parallel variant of [`batch::log`].
+pub fn log(buf: &mut [f64], base: f64, layout: Layout) -> Result<(), BatchError> {
+    map(buf, |b| batch::log(b, base, layout))
+}
+
+/// Parallel variant of [`batch::convert`].
+pub fn convert(buf: &mut [f64], from: Layout, to: Layout) -> Result<(), BatchError> {
+    map(buf, |b| batch::convert(b, from, to))
+}
+
+/// Parallel variant of [`batch::norm`].
+pub fn norm(buf: &[f64], layout: Layout) -> Result<Vec<f64>, BatchError> {
+    collect(buf, |b| batch::norm(b, layout))
+}
+
+/// Parallel variant of [`batch::angle`].
+pub fn angle(buf: &[f64], layout: Layout) -> Result<Vec<f64>, BatchError> {
+    collect(buf, |b| batch::angle(b, layout))
+}
+
+/// Parallel variant of [`batch::sum`], yielding exactly the same result.
+pub fn sum(buf: &[f64], layout: Layout) -> Result<Phasor, BatchError> {
+    let n = pairs(buf)?;
+    Ok(reduce::par_sum(n, |i| layout.load(&buf[2 * i..])))
+}
+
+/// Parallel variant of [`batch::dot`], yielding exactly the same result.
+pub fn dot(lhs: &[f64], rhs: &[f64], layout: Layout) -> Result<Phasor, BatchError> {
+    let n = pairs(lhs)?;
+    let step = if batch::broadcast(lhs, rhs)? { 0 } else { 2 };
+    Ok(reduce::par_sum(n, |i| {
+        layout.load(&lhs[2 * i..]) * layout.load(&rhs[step * i..])
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy;
+    use crate::test_util::{bits, flatten};
+    use proptest::collection::vec;
+    use rayon::ThreadPoolBuilder;
+    use test_strategy::proptest;
+
+    fn with_threads<R: Send>(n: usize, f: impl FnOnce() -> R + Send) -> R {
+        ThreadPoolBuilder::new()
+            .num_threads(n)
+            .build()
+            .unwrap()
+            .install(f)
+    }
+
+    #[proptest(cases = 16)]
+    fn elementwise_ops_match_sequential_ops(
+        #[strategy(vec(strategy::any(), 0..3 * GRAIN))] ps: Vec<Phasor>,
+        #[strategy(vec(strategy::any(), #ps.len()))] qs: Vec<Phasor>,
+    ) {
+        let (lhs, rhs) = (flatten(&ps), flatten(&qs));
+
+        let (mut p, mut s) = (lhs.clone(), lhs.clone());
+        assert_eq!(mul(&mut p, &rhs, Layout::Phasor), Ok(()));
+        assert_eq!(batch::mul(&mut s, &rhs, Layout::Phasor), Ok(()));
+        assert_eq!(bits(&p), bits(&s));
+
+        let (mut p, mut s) = (lhs.clone(), lhs.clone());
+        assert_eq!(sub(&mut p, &rhs[..2.min(rhs.len())], Layout::Rect), Ok(()));
+        assert_eq!(
+            batch::sub(&mut s, &rhs[..2.min(rhs.len())], Layout::Rect),
+            Ok(())
+        );
+        assert_eq!(bits(&p), bits(&s));
+
+        let (mut p, mut s) = (lhs.clone(), lhs.clone());
+        assert_eq!(exp(&mut p, Layout::Phasor), Ok(()));
+        assert_eq!(batch::exp(&mut s, Layout::Phasor), Ok(()));
+        assert_eq!(bits(&p), bits(&s));
+
+        assert_eq!(
+            norm(&lhs, Layout::Phasor).map(|n| bits(&n)),
+            batch::norm(&lhs, Layout::Phasor).map(|n| bits(&n))
+        );
+    }
+
+    #[proptest(cases = 16)]
+    fn reductions_are_independent_of_the_number_of_threads(
+        #[strategy(vec(strategy::finite(), 0..5000))] ps: Vec<Phasor>,
+        #[strategy(vec(strategy::finite(), #ps.len()))] qs: Vec<Phasor>,
+    ) {
+        let (lhs, rhs) = (flatten(&ps), flatten(&qs));
+
+        let s = batch::sum(&lhs, Layout::Phasor).unwrap();
+        let d = batch::dot(&lhs, &rhs, Layout::Phasor).unwrap();
+
+        for n in [1, 2, 3, 8] {
+            let p = with_threads(n, || sum(&lhs, Layout::Phasor)).unwrap();
+            assert_eq!(p.to_le_bytes(), s.to_le_bytes());
+
+            let p = with_threads(n, || dot(&lhs, &rhs, Layout::Phasor)).unwrap();
+            assert_eq!(p.to_le_bytes(), d.to_le_bytes());
+        }
+    }
+
+    #[test]
+    fn lengths_are_checked_up_front() {
+        let mut lhs = vec![1f64; 2 * GRAIN + 2];
+        let rhs = vec![1f64; 2 * GRAIN];
+
+        assert_eq!(
+            add(&mut lhs, &rhs, Layout::Phasor),
+            Err(BatchError::LengthMismatch(2 * GRAIN + 2, 2 * GRAIN))
+        );
+
+        assert_eq!(lhs, vec![1f64; 2 * GRAIN + 2]);
+        assert_eq!(
+            sum(&[0f64; 3], Layout::Phasor),
+            Err(BatchError::OddLength(3))
+        );
+    }
+}
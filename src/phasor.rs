@@ -1,40 +1,69 @@
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 use wasm_bindgen::prelude::*;
 
-#[cfg(test)]
-use test_strategy::Arbitrary;
-
 mod add;
 mod angle;
 mod approx;
+mod bytes;
+mod canonical;
+mod checked;
 mod classify;
 mod conj;
+mod consts;
+mod continuation;
+mod convert;
 mod cosh;
 mod display;
 mod div;
 mod exp;
+mod finite;
 mod imag;
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+mod json;
 mod ln;
 mod log;
+mod markup;
 mod mul;
 mod neg;
 mod norm;
+mod not_nan;
 mod polar;
 mod real;
 mod recip;
 mod rect;
+mod rotate;
 mod sinh;
+mod slice;
+mod sqrt;
 mod sub;
+mod tolerance;
+
+pub use self::approx::UlpsDistance;
+pub use canonical::Canonical;
+pub use checked::{Operation, PhasorError};
+pub use continuation::{Continuation, Multivalued};
+pub use display::{AngleUnit, Format, Formatted, Notation};
+pub use finite::Finite;
+pub use not_nan::NotNan;
+pub use slice::{Mismatch, Phasors};
+pub use tolerance::PolarTolerance;
 
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
-#[cfg_attr(test, derive(Arbitrary))]
-#[cfg_attr(target_arch = "wasm32", wasm_bindgen(inspectable))]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen(inspectable)
+)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "phasor", frozen, from_py_object, get_all)
+)]
+#[repr(C)]
 pub struct Phasor {
     pub mag: f64,
     pub tan: f64,
 }
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 #[wasm_bindgen]
 impl Phasor {
     #[wasm_bindgen(constructor)]
@@ -46,16 +75,17 @@ impl Phasor {
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 #[wasm_bindgen]
 pub fn i(im: Option<f64>) -> Phasor {
-    Phasor::new(im.or(Some(1f64)), Some(f64::INFINITY))
+    Phasor::quarter_turns(im.unwrap_or(1f64), 1)
 }
 
 #[cfg(test)]
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 mod test {
     use super::*;
+    use convert::cis;
     use polar::polar;
     use rect::rect;
     use std::f64::consts::{LN_10, PI};
@@ -80,6 +110,36 @@ mod test {
         assert!(s.abs_diff_eq(&polar(5f64, Some(4f64.atan2(3f64))), None));
     }
 
+    #[wasm_bindgen_test]
+    fn consts() {
+        assert!(Phasor::one()
+            .mul(&Phasor::imaginary_unit())
+            .ulps_eq(&i(None), None, None));
+        assert!(Phasor::neg_one().ulps_eq(&rect(-1f64, None), None, None));
+        assert!(Phasor::neg_imaginary_unit().ulps_eq(&i(Some(-1f64)), None, None));
+        assert!(Phasor::zero().is_zero());
+        assert!(Phasor::infinity().is_infinite());
+        assert!(Phasor::nan().is_nan());
+    }
+
+    #[wasm_bindgen_test]
+    fn convert() {
+        assert!(cis(PI / 2f64).ulps_eq(&i(None), Some(1E-15), None));
+        assert_eq!(rect(3f64, Some(4f64)).to_rect_array(), vec![3f64, 4f64]);
+        assert_eq!(i(Some(4f64)).to_polar_array(), vec![4f64, PI / 2f64]);
+    }
+
+    #[wasm_bindgen_test]
+    fn rotate() {
+        let p = rect(3f64, Some(4f64));
+        assert!(p
+            .rotate_by(&i(Some(2f64)))
+            .ulps_eq(&rect(-4f64, Some(3f64)), None, None));
+        assert!(p.unit().with_norm(5f64).ulps_eq(&p, None, None));
+        assert!(rect(5f64, None).with_angle_of(&p).ulps_eq(&p, None, None));
+        assert!(p.angle_between(&p).ulps_eq(&rect(1f64, None), None, None));
+    }
+
     #[wasm_bindgen_test]
     fn real() {
         assert_eq!(i(Some(4f64)).real(), 0f64);
@@ -106,6 +166,12 @@ mod test {
         assert_eq!(rect(3f64, Some(4f64)).angle(), 4f64.atan2(3f64));
     }
 
+    #[wasm_bindgen_test]
+    fn format() {
+        let p = rect(3f64, Some(4f64));
+        assert_eq!(p.format_with(None).unwrap(), p.to_string());
+    }
+
     #[wasm_bindgen_test]
     fn add() {
         let p = rect(3f64, None);
@@ -169,6 +235,17 @@ mod test {
         assert!(p.ln().ulps_eq(&q, None, None));
     }
 
+    #[wasm_bindgen_test]
+    fn branches() {
+        let p = rect(3f64, Some(4f64));
+        let q = rect(5f64.ln(), Some(4f64.atan2(3f64) - 2f64 * PI));
+        assert!(p.ln_branch(-1).ulps_eq(&q, None, None));
+
+        let p = rect(-4f64, None);
+        assert!(p.sqrt().ulps_eq(&i(Some(2f64)), None, None));
+        assert!(p.sqrt_branch(1).ulps_eq(&i(Some(-2f64)), None, None));
+    }
+
     #[wasm_bindgen_test]
     fn log() {
         let p = rect(3f64, Some(4f64));
@@ -2,7 +2,7 @@ use super::Phasor;
 use crate::trig::{cosatan, cosatan2, sinatan, sinatan2};
 use std::{num::FpCategory::Zero, ops::Add};
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 use wasm_bindgen::prelude::*;
 
 impl Add for Phasor {
@@ -36,7 +36,7 @@ impl Add for Phasor {
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 #[wasm_bindgen]
 impl Phasor {
     pub fn add(&self, rhs: &Phasor) -> Phasor {
@@ -1,14 +1,15 @@
 use super::Phasor;
-use std::f64::{consts::PI, NAN};
+use crate::Angle;
+use std::f64::consts::PI;
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 use wasm_bindgen::prelude::*;
 
-#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), wasm_bindgen)]
 impl Phasor {
     pub fn angle(&self) -> f64 {
         if self.is_nan() {
-            NAN
+            f64::NAN
         } else if self.mag.is_sign_positive() {
             self.tan.atan()
         } else {
@@ -17,11 +18,37 @@ impl Phasor {
     }
 }
 
+impl Phasor {
+    /// The angle as an [`Angle`], which is exact for purely real and purely imaginary phasors.
+    pub fn arg(&self) -> Angle {
+        let positive = self.mag.is_sign_positive();
+
+        if self.is_nan() {
+            Angle::radians(f64::NAN)
+        } else if self.tan == 0f64 && positive {
+            Angle::degrees(0f64)
+        } else if self.tan == 0f64 && self.tan.is_sign_negative() {
+            Angle::degrees(180f64)
+        } else if self.tan == 0f64 {
+            // Whole quarter turns wrap `-π` around to `π`, so it's kept in radians instead.
+            Angle::radians(-PI)
+        } else if self.tan.is_infinite() {
+            Angle::degrees(if positive == self.tan.is_sign_positive() {
+                90f64
+            } else {
+                -90f64
+            })
+        } else {
+            Angle::radians(self.angle())
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::arbitrary::{any, *};
-    use approx::assert_ulps_eq;
+    use approx::{assert_relative_eq, assert_ulps_eq};
     use test_strategy::proptest;
 
     #[proptest]
@@ -42,6 +69,42 @@ mod tests {
         assert_ulps_eq!(p.angle(), p.imag().atan2(p.real()), epsilon = 1E-11);
     }
 
+    #[proptest]
+    fn arg_equals_angle(#[strategy(not_nan())] mag: f64, #[strategy(not_nan())] tan: f64) {
+        let p = Phasor { mag, tan };
+
+        assert_relative_eq!(
+            Phasor::polar(1f64, p.arg()),
+            Phasor::polar(1f64, p.angle()),
+            epsilon = 1E-15
+        );
+    }
+
+    #[proptest]
+    fn arg_is_exact_for_right_angles(
+        #[strategy(regular())] mag: f64,
+        #[strategy(-100i32..100)] k: i32,
+    ) {
+        let p = Phasor::quarter_turns(mag.abs(), k);
+        assert_eq!(p.arg(), Angle::degrees(90f64 * k as f64));
+        assert_eq!(Phasor::polar(p.norm(), p.arg()), p);
+    }
+
+    #[proptest]
+    fn arg_agrees_with_angle_on_the_real_axis(
+        #[strategy(nonzero())] mag: f64,
+        #[strategy(zero())] tan: f64,
+    ) {
+        let p = Phasor { mag, tan };
+        assert_eq!(p.arg().to_radians(), p.angle());
+    }
+
+    #[proptest]
+    fn arg_is_nan_if_phasor_is_nan(#[strategy(nan())] mag: f64, #[strategy(any())] tan: f64) {
+        assert!(Phasor { mag, tan }.arg().to_radians().is_nan());
+        assert!(Phasor { mag: tan, tan: mag }.arg().to_radians().is_nan());
+    }
+
     #[proptest]
     fn is_nan_if_magnitude_is_nan(#[strategy(nan())] mag: f64, #[strategy(any())] tan: f64) {
         let p = Phasor { mag, tan };
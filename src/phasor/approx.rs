@@ -1,8 +1,9 @@
 use super::Phasor;
 use crate::trig::{cosatan, sinatan};
 use ::approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use std::fmt::{self, Display, Formatter};
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 use wasm_bindgen::prelude::*;
 
 fn eq(p: &Phasor, q: &Phasor, cmp: impl Fn(f64, f64) -> bool) -> bool {
@@ -13,6 +14,69 @@ fn eq(p: &Phasor, q: &Phasor, cmp: impl Fn(f64, f64) -> bool) -> bool {
     cmp(p.mag.abs(), q.mag.abs()) && cmp(sp * cq, cp * sq) && (sp * sq + cp * cq).signum() == sign
 }
 
+/// The distance in [units in the last place] between two phasors, broken down into the terms
+/// compared by [`UlpsEq`].
+///
+/// Unlike [`UlpsEq`], distances are counted across zero, so that tiny values of opposite signs
+/// are reported as close even though they never compare equal without an absolute epsilon.
+///
+/// [units in the last place]: https://en.wikipedia.org/wiki/Unit_in_the_last_place
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct UlpsDistance {
+    /// The distance between the norms.
+    pub mag: u64,
+    /// The distance between `sin(θ₁)·cos(θ₂)` and `cos(θ₁)·sin(θ₂)`, which is zero for parallel
+    /// phasors.
+    pub angle: u64,
+    /// Whether the phasors point in opposite half-planes.
+    pub opposite: bool,
+}
+
+impl Display for UlpsDistance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "magnitude: {} ulps, angle: {} ulps",
+            self.mag, self.angle
+        )?;
+
+        if self.opposite {
+            write!(f, ", opposite directions")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn ulps(x: f64, y: f64) -> u64 {
+    if x.is_nan() || y.is_nan() {
+        return u64::MAX;
+    }
+
+    // Maps the sign-magnitude representation onto two's complement, so that consecutive floats
+    // are consecutive integers and both zeros coincide.
+    let key = |x: f64| match x.to_bits() as i64 {
+        b if b < 0 => i64::MIN.wrapping_sub(b),
+        b => b,
+    };
+
+    key(x).abs_diff(key(y))
+}
+
+impl Phasor {
+    pub fn ulps_distance(&self, other: &Phasor) -> UlpsDistance {
+        let sign = self.mag.signum() * other.mag.signum();
+        let (sp, cp) = (sinatan(self.tan), cosatan(self.tan));
+        let (sq, cq) = (sinatan(other.tan), cosatan(other.tan));
+
+        UlpsDistance {
+            mag: ulps(self.mag.abs(), other.mag.abs()),
+            angle: ulps(sp * cq, cp * sq),
+            opposite: (sp * sq + cp * cq).signum() != sign,
+        }
+    }
+}
+
 impl AbsDiffEq for Phasor {
     type Epsilon = f64;
 
@@ -45,7 +109,7 @@ impl UlpsEq for Phasor {
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 #[wasm_bindgen]
 impl Phasor {
     #[wasm_bindgen(js_name = "absDiffEq")]
@@ -82,6 +146,88 @@ mod tests {
     use approx::*;
     use test_strategy::proptest;
 
+    #[test]
+    fn ulps_counts_representable_floats_in_between() {
+        assert_eq!(ulps(1f64, 1f64), 0);
+        assert_eq!(ulps(0f64, -0f64), 0);
+        assert_eq!(ulps(1f64, 1f64 + f64::EPSILON), 1);
+        assert_eq!(ulps(-f64::MIN_POSITIVE, f64::MIN_POSITIVE), 1 << 53);
+        assert_eq!(ulps(f64::MAX, f64::INFINITY), 1);
+        assert_eq!(ulps(f64::NAN, 0f64), u64::MAX);
+    }
+
+    #[proptest]
+    fn ulps_is_symmetric(#[strategy(any())] x: f64, #[strategy(any())] y: f64) {
+        assert_eq!(ulps(x, y), ulps(y, x));
+    }
+
+    #[proptest]
+    fn ulps_distance_is_zero_for_same(
+        #[strategy(not_nan())] mag: f64,
+        #[strategy(not_nan())] tan: f64,
+    ) {
+        let p = Phasor { mag, tan };
+        assert_eq!(p.ulps_distance(&p), UlpsDistance::default());
+    }
+
+    #[proptest]
+    fn ulps_distance_is_consistent_with_ulps_eq(
+        #[strategy(any())] a: f64,
+        #[strategy(any())] b: f64,
+        #[strategy(any())] c: f64,
+        #[strategy(any())] d: f64,
+        max: u32,
+    ) {
+        let p = Phasor { mag: a, tan: b };
+        let q = Phasor { mag: c, tan: d };
+        let d = p.ulps_distance(&q);
+
+        if UlpsEq::ulps_eq(&p, &q, 0f64, max) {
+            assert!(d.mag <= max.into());
+            assert!(d.angle <= max.into());
+            assert!(!d.opposite);
+        }
+    }
+
+    #[proptest]
+    fn ulps_distance_tells_magnitude_from_angle(
+        #[strategy(normal())] mag: f64,
+        #[strategy(normal())] tan: f64,
+    ) {
+        let p = Phasor { mag, tan };
+
+        let q = Phasor {
+            mag: f64::from_bits(mag.to_bits() ^ 1),
+            tan,
+        };
+
+        assert_eq!(
+            p.ulps_distance(&q),
+            UlpsDistance {
+                mag: 1,
+                angle: 0,
+                opposite: false
+            }
+        );
+
+        let q = Phasor { mag: -mag, tan };
+        assert!(p.ulps_distance(&q).opposite);
+    }
+
+    #[test]
+    fn ulps_distance_is_displayed_by_component() {
+        let d = UlpsDistance {
+            mag: 3,
+            angle: 7,
+            opposite: true,
+        };
+
+        assert_eq!(
+            d.to_string(),
+            "magnitude: 3 ulps, angle: 7 ulps, opposite directions"
+        );
+    }
+
     #[proptest]
     fn abs_diff_eq_scaled(#[strategy(normal())] mag: f64, #[strategy(not_nan())] tan: f64) {
         let p = Phasor {
@@ -0,0 +1,112 @@
+use super::Phasor;
+
+impl Phasor {
+    /// Returns the memory representation of this phasor as a byte array in little-endian byte
+    /// order, that is the magnitude followed by the tangent, each as per [`f64::to_le_bytes`].
+    pub fn to_le_bytes(self) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&self.mag.to_le_bytes());
+        bytes[8..].copy_from_slice(&self.tan.to_le_bytes());
+        bytes
+    }
+
+    /// Returns the memory representation of this phasor as a byte array in big-endian byte
+    /// order, that is the magnitude followed by the tangent, each as per [`f64::to_be_bytes`].
+    pub fn to_be_bytes(self) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&self.mag.to_be_bytes());
+        bytes[8..].copy_from_slice(&self.tan.to_be_bytes());
+        bytes
+    }
+
+    /// Creates a phasor from its representation as a byte array in little-endian byte order.
+    pub fn from_le_bytes(bytes: [u8; 16]) -> Self {
+        let (mag, tan) = bytes.split_at(8);
+
+        Phasor {
+            mag: f64::from_le_bytes(mag.try_into().unwrap()),
+            tan: f64::from_le_bytes(tan.try_into().unwrap()),
+        }
+    }
+
+    /// Creates a phasor from its representation as a byte array in big-endian byte order.
+    pub fn from_be_bytes(bytes: [u8; 16]) -> Self {
+        let (mag, tan) = bytes.split_at(8);
+
+        Phasor {
+            mag: f64::from_be_bytes(mag.try_into().unwrap()),
+            tan: f64::from_be_bytes(tan.try_into().unwrap()),
+        }
+    }
+}
+
+// SAFETY: Phasor is `#[repr(C)]` and consists of two `f64`s, so it has no padding and every bit
+// pattern is valid, including all zeros.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Phasor {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Phasor {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_strategy::proptest;
+
+    #[proptest]
+    fn le_bytes_round_trip(p: Phasor) {
+        let q = Phasor::from_le_bytes(p.to_le_bytes());
+        assert_eq!(p.mag.to_bits(), q.mag.to_bits());
+        assert_eq!(p.tan.to_bits(), q.tan.to_bits());
+    }
+
+    #[proptest]
+    fn be_bytes_round_trip(p: Phasor) {
+        let q = Phasor::from_be_bytes(p.to_be_bytes());
+        assert_eq!(p.mag.to_bits(), q.mag.to_bits());
+        assert_eq!(p.tan.to_bits(), q.tan.to_bits());
+    }
+
+    #[proptest]
+    fn le_bytes_are_magnitude_followed_by_tangent(p: Phasor) {
+        let bytes = p.to_le_bytes();
+        assert_eq!(bytes[..8], p.mag.to_le_bytes());
+        assert_eq!(bytes[8..], p.tan.to_le_bytes());
+    }
+
+    #[proptest]
+    fn be_bytes_are_magnitude_followed_by_tangent(p: Phasor) {
+        let bytes = p.to_be_bytes();
+        assert_eq!(bytes[..8], p.mag.to_be_bytes());
+        assert_eq!(bytes[8..], p.tan.to_be_bytes());
+    }
+
+    #[test]
+    fn has_the_layout_of_two_f64() {
+        use std::mem::{align_of, offset_of, size_of};
+
+        assert_eq!(size_of::<Phasor>(), 2 * size_of::<f64>());
+        assert_eq!(align_of::<Phasor>(), align_of::<f64>());
+        assert_eq!(offset_of!(Phasor, mag), 0);
+        assert_eq!(offset_of!(Phasor, tan), size_of::<f64>());
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[proptest]
+    fn slices_can_be_reinterpreted_without_copying(v: Vec<Phasor>) {
+        let floats: &[f64] = bytemuck::cast_slice(&v);
+        assert_eq!(floats.len(), 2 * v.len());
+
+        for (p, f) in v.iter().zip(floats.chunks(2)) {
+            assert_eq!(p.mag.to_bits(), f[0].to_bits());
+            assert_eq!(p.tan.to_bits(), f[1].to_bits());
+        }
+
+        let bytes: &[u8] = bytemuck::cast_slice(&v);
+        assert_eq!(bytes.as_ptr(), v.as_ptr().cast());
+
+        for (p, b) in v.iter().zip(bytes.chunks(16)) {
+            assert_eq!(b, [p.mag.to_ne_bytes(), p.tan.to_ne_bytes()].concat());
+        }
+    }
+}
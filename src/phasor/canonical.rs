@@ -0,0 +1,328 @@
+use super::Phasor;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen::prelude::*;
+
+#[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), wasm_bindgen)]
+impl Phasor {
+    pub fn canonicalize(self) -> Self {
+        if self.is_nan() {
+            Phasor {
+                mag: f64::NAN,
+                tan: f64::NAN,
+            }
+        } else if self.is_zero() {
+            Phasor {
+                mag: 0f64,
+                tan: 0f64,
+            }
+        } else if self.tan.is_infinite() {
+            Phasor {
+                mag: self.mag * self.tan.signum(),
+                tan: f64::INFINITY,
+            }
+        } else if self.tan == 0f64 {
+            // Negative reals lie on the branch cut, which is approached from above like `rect` does.
+            Phasor {
+                mag: self.mag,
+                tan: 0f64.copysign(self.mag),
+            }
+        } else {
+            self
+        }
+    }
+}
+
+/// A [`Phasor`] in canonical form, which compares equal to another if and only if both represent
+/// the same complex number, treating all _NaN_s as equal.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Canonical<T>(T);
+
+impl<T> Canonical<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl From<Phasor> for Canonical<Phasor> {
+    fn from(p: Phasor) -> Self {
+        Canonical(p.canonicalize())
+    }
+}
+
+impl From<Canonical<Phasor>> for Phasor {
+    fn from(c: Canonical<Phasor>) -> Self {
+        c.0
+    }
+}
+
+impl Deref for Canonical<Phasor> {
+    type Target = Phasor;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl PartialEq for Canonical<Phasor> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Canonical<Phasor> {}
+
+impl Hash for Canonical<Phasor> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.mag.to_bits().hash(state);
+        self.0.tan.to_bits().hash(state);
+    }
+}
+
+impl PartialOrd for Canonical<Phasor> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders by norm first and then by angle, with _NaN_ greater than any other value.
+impl Ord for Canonical<Phasor> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (p, q) = (&self.0, &other.0);
+
+        p.norm()
+            .total_cmp(&q.norm())
+            .then_with(|| p.angle().total_cmp(&q.angle()))
+            .then_with(|| p.tan.total_cmp(&q.tan))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arbitrary::{any, *};
+    use approx::assert_ulps_eq;
+    use proptest::{prop_assume, sample::select};
+    use std::collections::{hash_map::DefaultHasher, HashSet};
+    use std::f64::consts::PI;
+    use test_strategy::proptest;
+
+    /// Values whose combinations often denote the same complex number with different encodings.
+    const SPECIAL: &[f64] = &[
+        0f64,
+        -0f64,
+        1f64,
+        -1f64,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+
+    fn hash(c: &Canonical<Phasor>) -> u64 {
+        let mut h = DefaultHasher::new();
+        c.hash(&mut h);
+        h.finish()
+    }
+
+    #[proptest]
+    fn is_idempotent(#[strategy(any())] mag: f64, #[strategy(any())] tan: f64) {
+        let p = Phasor { mag, tan }.canonicalize();
+        let q = p.canonicalize();
+
+        assert_eq!(p.mag.to_bits(), q.mag.to_bits());
+        assert_eq!(p.tan.to_bits(), q.tan.to_bits());
+    }
+
+    #[proptest]
+    fn preserves_value(#[strategy(nonzero())] mag: f64, #[strategy(not_nan())] tan: f64) {
+        let p = Phasor { mag, tan };
+        assert_ulps_eq!(p.canonicalize(), p);
+    }
+
+    #[proptest]
+    fn preserves_angle(#[strategy(nonzero())] mag: f64, #[strategy(not_nan())] tan: f64) {
+        let p = Phasor { mag, tan };
+        prop_assume!(tan != 0f64 || mag > 0f64);
+        assert_eq!(p.canonicalize().angle(), p.angle());
+    }
+
+    #[proptest]
+    fn maps_negative_reals_to_the_upper_side_of_the_branch_cut(
+        #[strategy(negative())] mag: f64,
+        #[strategy(zero())] tan: f64,
+    ) {
+        let p = Phasor { mag, tan }.canonicalize();
+        let q = Phasor::rect(mag, 0f64);
+
+        assert_eq!(p.mag.to_bits(), q.mag.to_bits());
+        assert_eq!(p.tan.to_bits(), q.tan.to_bits());
+        assert_eq!(p.angle(), PI);
+    }
+
+    #[proptest]
+    fn equal_phasors_have_identical_canonical_forms(
+        #[strategy(select(SPECIAL))] a: f64,
+        #[strategy(select(SPECIAL))] b: f64,
+        #[strategy(select(SPECIAL))] c: f64,
+        #[strategy(select(SPECIAL))] d: f64,
+    ) {
+        let p = Canonical::from(Phasor { mag: a, tan: b });
+        let q = Canonical::from(Phasor { mag: c, tan: d });
+
+        if p == q {
+            assert_eq!(p.mag.to_bits(), q.mag.to_bits());
+            assert_eq!(p.tan.to_bits(), q.tan.to_bits());
+        }
+    }
+
+    #[proptest]
+    fn preserves_real_and_imaginary_parts(
+        #[strategy(finite())] mag: f64,
+        #[strategy(not_nan())] tan: f64,
+    ) {
+        let p = Phasor { mag, tan };
+        assert_eq!(p.canonicalize().real(), p.real() + 0f64);
+        assert_eq!(p.canonicalize().imag(), p.imag() + 0f64);
+    }
+
+    #[proptest]
+    fn zero_is_unique(
+        #[strategy(zero())] a: f64,
+        #[strategy(not_nan())] b: f64,
+        #[strategy(zero())] c: f64,
+        #[strategy(not_nan())] d: f64,
+    ) {
+        let p = Canonical::from(Phasor { mag: a, tan: b });
+        let q = Canonical::from(Phasor { mag: c, tan: d });
+
+        assert_eq!(p, q);
+        assert_eq!(hash(&p), hash(&q));
+    }
+
+    #[proptest]
+    fn nan_is_unique(
+        #[strategy(any())] a: f64,
+        #[strategy(nan())] b: f64,
+        #[strategy(nan())] c: f64,
+        #[strategy(any())] d: f64,
+    ) {
+        let p = Canonical::from(Phasor { mag: a, tan: b });
+        let q = Canonical::from(Phasor { mag: c, tan: d });
+
+        assert_eq!(p, q);
+        assert_eq!(hash(&p), hash(&q));
+    }
+
+    #[proptest]
+    fn signed_zero_tangents_are_equal(
+        #[strategy(nonzero())] mag: f64,
+        #[strategy(zero())] tan: f64,
+    ) {
+        let p = Canonical::from(Phasor { mag, tan });
+        let q = Canonical::from(Phasor { mag, tan: -tan });
+
+        assert_eq!(p, q);
+        assert_eq!(hash(&p), hash(&q));
+    }
+
+    #[proptest]
+    fn opposite_infinite_tangents_are_equal_if_magnitude_is_negated(
+        #[strategy(nonzero())] mag: f64,
+        #[strategy(infinite())] tan: f64,
+    ) {
+        let p = Canonical::from(Phasor { mag, tan });
+        let q = Canonical::from(Phasor {
+            mag: -mag,
+            tan: -tan,
+        });
+
+        assert_eq!(p, q);
+        assert_eq!(hash(&p), hash(&q));
+    }
+
+    #[proptest]
+    fn opposite_phasors_are_different(
+        #[strategy(nonzero())] mag: f64,
+        #[strategy(not_nan())] tan: f64,
+    ) {
+        let p = Canonical::from(Phasor { mag, tan });
+        let q = Canonical::from(Phasor { mag: -mag, tan });
+
+        assert_ne!(p, q);
+    }
+
+    #[proptest]
+    fn order_is_consistent_with_equality(p: Phasor, q: Phasor) {
+        let (p, q) = (Canonical::from(p), Canonical::from(q));
+
+        assert_eq!(p.cmp(&q) == Ordering::Equal, p == q);
+        assert_eq!(p.cmp(&q), q.cmp(&p).reverse());
+    }
+
+    #[proptest]
+    fn orders_by_norm(
+        #[strategy(not_nan())] a: f64,
+        #[strategy(not_nan())] b: f64,
+        #[strategy(not_nan())] c: f64,
+        #[strategy(not_nan())] d: f64,
+    ) {
+        let p = Canonical::from(Phasor { mag: a, tan: b });
+        let q = Canonical::from(Phasor { mag: c, tan: d });
+
+        if p.norm() < q.norm() {
+            assert!(p < q);
+        }
+    }
+
+    #[proptest]
+    fn nan_is_greatest(p: Phasor, #[strategy(nan())] tan: f64) {
+        let nan = Canonical::from(Phasor { mag: 0f64, tan });
+        assert!(Canonical::from(p) <= nan);
+    }
+
+    #[test]
+    fn deduplicates_equivalent_phasors() {
+        let set: HashSet<_> = [
+            Phasor {
+                mag: 1f64,
+                tan: 0f64,
+            },
+            Phasor {
+                mag: 1f64,
+                tan: -0f64,
+            },
+            Phasor {
+                mag: 2f64,
+                tan: f64::INFINITY,
+            },
+            Phasor {
+                mag: -2f64,
+                tan: f64::NEG_INFINITY,
+            },
+            Phasor {
+                mag: 0f64,
+                tan: 1f64,
+            },
+            Phasor {
+                mag: -0f64,
+                tan: -1f64,
+            },
+            Phasor {
+                mag: f64::NAN,
+                tan: 0f64,
+            },
+            Phasor {
+                mag: 0f64,
+                tan: f64::NAN,
+            },
+        ]
+        .into_iter()
+        .map(Canonical::from)
+        .collect();
+
+        assert_eq!(set.len(), 4);
+    }
+}
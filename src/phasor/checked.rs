@@ -0,0 +1,399 @@
+use super::Phasor;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// An operation along with its operands.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Operation {
+    Add(Phasor, Phasor),
+    Sub(Phasor, Phasor),
+    Mul(Phasor, Phasor),
+    Div(Phasor, Phasor),
+    Recip(Phasor),
+    Exp(Phasor),
+    Ln(Phasor),
+    Log(Phasor, f64),
+    Sinh(Phasor),
+    Cosh(Phasor),
+}
+
+impl Operation {
+    fn is_nan(&self) -> bool {
+        match *self {
+            Operation::Add(p, q)
+            | Operation::Sub(p, q)
+            | Operation::Mul(p, q)
+            | Operation::Div(p, q) => p.is_nan() || q.is_nan(),
+            Operation::Log(p, b) => p.is_nan() || b.is_nan(),
+            Operation::Recip(p)
+            | Operation::Exp(p)
+            | Operation::Ln(p)
+            | Operation::Sinh(p)
+            | Operation::Cosh(p) => p.is_nan(),
+        }
+    }
+
+    fn is_finite(&self) -> bool {
+        match *self {
+            Operation::Add(p, q)
+            | Operation::Sub(p, q)
+            | Operation::Mul(p, q)
+            | Operation::Div(p, q) => p.is_finite() && q.is_finite(),
+            Operation::Log(p, b) => p.is_finite() && b.is_finite(),
+            Operation::Recip(p)
+            | Operation::Exp(p)
+            | Operation::Ln(p)
+            | Operation::Sinh(p)
+            | Operation::Cosh(p) => p.is_finite(),
+        }
+    }
+
+    fn is_division_by_zero(&self) -> bool {
+        match *self {
+            Operation::Div(_, q) => q.is_zero(),
+            Operation::Recip(p) | Operation::Ln(p) => p.is_zero(),
+            Operation::Log(p, b) => p.is_zero() || b == 1f64,
+            _ => false,
+        }
+    }
+
+    fn eval(&self) -> Phasor {
+        match *self {
+            Operation::Add(p, q) => p + q,
+            Operation::Sub(p, q) => p - q,
+            Operation::Mul(p, q) => p * q,
+            Operation::Div(p, q) => p / q,
+            Operation::Recip(p) => p.recip(),
+            Operation::Exp(p) => p.exp(),
+            Operation::Ln(p) => p.ln(),
+            Operation::Log(p, b) => p.log(b),
+            Operation::Sinh(p) => p.sinh(),
+            Operation::Cosh(p) => p.cosh(),
+        }
+    }
+
+    fn check(self) -> Result<Phasor, PhasorError> {
+        let r = self.eval();
+
+        if self.is_nan() {
+            Err(PhasorError::NanOperand(self))
+        } else if r.is_nan() {
+            Err(PhasorError::Indeterminate(self))
+        } else if r.is_infinite() && self.is_finite() {
+            if self.is_division_by_zero() {
+                Err(PhasorError::DivisionByZero(self))
+            } else {
+                Err(PhasorError::Overflow(self))
+            }
+        } else {
+            Ok(r)
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::Add(p, q) => write!(f, "({p}) + ({q})"),
+            Operation::Sub(p, q) => write!(f, "({p}) - ({q})"),
+            Operation::Mul(p, q) => write!(f, "({p}) × ({q})"),
+            Operation::Div(p, q) => write!(f, "({p}) / ({q})"),
+            Operation::Recip(p) => write!(f, "1 / ({p})"),
+            Operation::Exp(p) => write!(f, "exp({p})"),
+            Operation::Ln(p) => write!(f, "ln({p})"),
+            Operation::Log(p, b) => write!(f, "log{b}({p})"),
+            Operation::Sinh(p) => write!(f, "sinh({p})"),
+            Operation::Cosh(p) => write!(f, "cosh({p})"),
+        }
+    }
+}
+
+/// The reason why a checked operation failed.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PhasorError {
+    /// At least one of the operands is _NaN_.
+    NanOperand(Operation),
+    /// The operation has no well defined result, e.g. `∞ - ∞`, `0 × ∞` or `0 / 0`.
+    Indeterminate(Operation),
+    /// The result is infinite because a finite operand was divided by zero.
+    DivisionByZero(Operation),
+    /// The result overflowed to infinity even though all operands are finite.
+    Overflow(Operation),
+}
+
+impl PhasorError {
+    pub fn operation(&self) -> &Operation {
+        match self {
+            PhasorError::NanOperand(op)
+            | PhasorError::Indeterminate(op)
+            | PhasorError::DivisionByZero(op)
+            | PhasorError::Overflow(op) => op,
+        }
+    }
+}
+
+impl Display for PhasorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PhasorError::NanOperand(op) => write!(f, "NaN operand in {op}"),
+            PhasorError::Indeterminate(op) => write!(f, "indeterminate form {op}"),
+            PhasorError::DivisionByZero(op) => write!(f, "division by zero in {op}"),
+            PhasorError::Overflow(op) => write!(f, "overflow in {op}"),
+        }
+    }
+}
+
+impl Error for PhasorError {}
+
+impl Phasor {
+    pub fn checked_add(self, rhs: Phasor) -> Result<Phasor, PhasorError> {
+        Operation::Add(self, rhs).check()
+    }
+
+    pub fn checked_sub(self, rhs: Phasor) -> Result<Phasor, PhasorError> {
+        Operation::Sub(self, rhs).check()
+    }
+
+    pub fn checked_mul(self, rhs: Phasor) -> Result<Phasor, PhasorError> {
+        Operation::Mul(self, rhs).check()
+    }
+
+    pub fn checked_div(self, rhs: Phasor) -> Result<Phasor, PhasorError> {
+        Operation::Div(self, rhs).check()
+    }
+
+    pub fn checked_recip(self) -> Result<Phasor, PhasorError> {
+        Operation::Recip(self).check()
+    }
+
+    pub fn checked_exp(self) -> Result<Phasor, PhasorError> {
+        Operation::Exp(self).check()
+    }
+
+    pub fn checked_ln(self) -> Result<Phasor, PhasorError> {
+        Operation::Ln(self).check()
+    }
+
+    pub fn checked_log(self, base: f64) -> Result<Phasor, PhasorError> {
+        Operation::Log(self, base).check()
+    }
+
+    pub fn checked_sinh(self) -> Result<Phasor, PhasorError> {
+        Operation::Sinh(self).check()
+    }
+
+    pub fn checked_cosh(self) -> Result<Phasor, PhasorError> {
+        Operation::Cosh(self).check()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arbitrary::{any, *};
+    use test_strategy::proptest;
+
+    #[proptest]
+    fn equals_unchecked_operation_if_ok(p: Phasor, q: Phasor) {
+        if let Ok(r) = p.checked_add(q) {
+            assert_eq!(r, p + q);
+        }
+
+        if let Ok(r) = p.checked_sub(q) {
+            assert_eq!(r, p - q);
+        }
+
+        if let Ok(r) = p.checked_mul(q) {
+            assert_eq!(r, p * q);
+        }
+
+        if let Ok(r) = p.checked_div(q) {
+            assert_eq!(r, p / q);
+        }
+
+        if let Ok(r) = p.checked_ln() {
+            assert_eq!(r, p.ln());
+        }
+    }
+
+    #[proptest]
+    fn is_never_nan(p: Phasor, q: Phasor) {
+        for r in [
+            p.checked_add(q),
+            p.checked_sub(q),
+            p.checked_mul(q),
+            p.checked_div(q),
+            p.checked_recip(),
+            p.checked_exp(),
+            p.checked_ln(),
+            p.checked_sinh(),
+            p.checked_cosh(),
+        ] {
+            assert!(!r.is_ok_and(|r| r.is_nan()));
+        }
+    }
+
+    #[proptest]
+    fn fails_if_magnitude_is_nan(
+        #[strategy(any())] a: f64,
+        #[strategy(any())] b: f64,
+        #[strategy(nan())] c: f64,
+        #[strategy(any())] d: f64,
+    ) {
+        let p = Phasor { mag: a, tan: b };
+        let q = Phasor { mag: c, tan: d };
+
+        assert!(matches!(
+            p.checked_add(q),
+            Err(PhasorError::NanOperand(Operation::Add(..)))
+        ));
+        assert!(matches!(
+            q.checked_sub(p),
+            Err(PhasorError::NanOperand(Operation::Sub(..)))
+        ));
+        assert!(matches!(
+            p.checked_mul(q),
+            Err(PhasorError::NanOperand(Operation::Mul(..)))
+        ));
+        assert!(matches!(
+            q.checked_div(p),
+            Err(PhasorError::NanOperand(Operation::Div(..)))
+        ));
+        assert!(matches!(
+            q.checked_ln(),
+            Err(PhasorError::NanOperand(Operation::Ln(..)))
+        ));
+    }
+
+    #[proptest]
+    fn fails_if_tangent_is_nan(#[strategy(any())] a: f64, #[strategy(nan())] b: f64) {
+        let p = Phasor { mag: a, tan: b };
+
+        assert!(matches!(
+            p.checked_exp(),
+            Err(PhasorError::NanOperand(Operation::Exp(..)))
+        ));
+    }
+
+    #[proptest]
+    fn fails_if_phasors_are_opposite_and_infinite(
+        #[strategy(infinite())] mag: f64,
+        #[strategy(not_nan())] tan: f64,
+    ) {
+        let p = Phasor { mag, tan };
+        let q = -p;
+
+        assert_eq!(
+            p.checked_add(q),
+            Err(PhasorError::Indeterminate(Operation::Add(p, q)))
+        );
+
+        assert_eq!(
+            p.checked_sub(p),
+            Err(PhasorError::Indeterminate(Operation::Sub(p, p)))
+        );
+    }
+
+    #[proptest]
+    fn fails_if_phasors_are_zero_and_infinite(
+        #[strategy(infinite())] a: f64,
+        #[strategy(not_nan())] b: f64,
+        #[strategy(zero())] c: f64,
+        #[strategy(not_nan())] d: f64,
+    ) {
+        let p = Phasor { mag: a, tan: b };
+        let q = Phasor { mag: c, tan: d };
+
+        assert_eq!(
+            p.checked_mul(q),
+            Err(PhasorError::Indeterminate(Operation::Mul(p, q)))
+        );
+    }
+
+    #[proptest]
+    fn fails_if_phasors_are_both_zero_or_both_infinite(
+        #[strategy(zero())] a: f64,
+        #[strategy(not_nan())] b: f64,
+        #[strategy(infinite())] c: f64,
+        #[strategy(not_nan())] d: f64,
+    ) {
+        let p = Phasor { mag: a, tan: b };
+        let q = Phasor { mag: c, tan: d };
+
+        assert_eq!(
+            p.checked_div(p),
+            Err(PhasorError::Indeterminate(Operation::Div(p, p)))
+        );
+
+        assert_eq!(
+            q.checked_div(q),
+            Err(PhasorError::Indeterminate(Operation::Div(q, q)))
+        );
+    }
+
+    #[proptest]
+    fn fails_if_finite_phasor_is_divided_by_zero(
+        #[strategy(regular())] a: f64,
+        #[strategy(not_nan())] b: f64,
+        #[strategy(zero())] c: f64,
+        #[strategy(not_nan())] d: f64,
+    ) {
+        let p = Phasor { mag: a, tan: b };
+        let q = Phasor { mag: c, tan: d };
+
+        assert_eq!(
+            p.checked_div(q),
+            Err(PhasorError::DivisionByZero(Operation::Div(p, q)))
+        );
+
+        assert_eq!(
+            q.checked_recip(),
+            Err(PhasorError::DivisionByZero(Operation::Recip(q)))
+        );
+
+        assert_eq!(
+            q.checked_ln(),
+            Err(PhasorError::DivisionByZero(Operation::Ln(q)))
+        );
+    }
+
+    #[proptest]
+    fn fails_if_finite_phasors_overflow(
+        #[strategy(1E300f64..f64::MAX)] a: f64,
+        #[strategy(1E300f64..f64::MAX)] b: f64,
+    ) {
+        let p = Phasor { mag: a, tan: 0f64 };
+        let q = Phasor { mag: b, tan: 0f64 };
+
+        assert_eq!(
+            p.checked_mul(q),
+            Err(PhasorError::Overflow(Operation::Mul(p, q)))
+        );
+    }
+
+    #[proptest]
+    fn succeeds_if_operands_are_infinite(
+        #[strategy(infinite())] a: f64,
+        #[strategy(not_nan())] b: f64,
+        #[strategy(finite())] c: f64,
+        #[strategy(not_nan())] d: f64,
+    ) {
+        let p = Phasor { mag: a, tan: b };
+        let q = Phasor { mag: c, tan: d };
+
+        assert_eq!(p.checked_add(q), Ok(p + q));
+        assert_eq!(p.checked_sub(q), Ok(p - q));
+    }
+
+    #[test]
+    fn error_message_names_the_operation() {
+        let p = Phasor {
+            mag: f64::INFINITY,
+            tan: 0f64,
+        };
+
+        let e = p.checked_sub(p).unwrap_err();
+        assert_eq!(e.operation(), &Operation::Sub(p, p));
+        assert_eq!(e.to_string(), "indeterminate form (inf∠0) - (inf∠0)");
+    }
+}
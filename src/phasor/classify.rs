@@ -1,7 +1,7 @@
 use super::Phasor;
 use std::num::FpCategory;
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 use wasm_bindgen::prelude::*;
 
 impl Phasor {
@@ -14,49 +14,106 @@ impl Phasor {
     }
 }
 
-#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), wasm_bindgen)]
 impl Phasor {
-    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = "isNaN"))]
+    #[cfg_attr(
+        all(target_arch = "wasm32", target_os = "unknown"),
+        wasm_bindgen(js_name = "isNaN")
+    )]
     pub fn is_nan(&self) -> bool {
         self.classify() == FpCategory::Nan
     }
 
-    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = "isInfinite"))]
+    #[cfg_attr(
+        all(target_arch = "wasm32", target_os = "unknown"),
+        wasm_bindgen(js_name = "isInfinite")
+    )]
     pub fn is_infinite(&self) -> bool {
         self.classify() == FpCategory::Infinite
     }
 
-    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = "isFinite"))]
+    #[cfg_attr(
+        all(target_arch = "wasm32", target_os = "unknown"),
+        wasm_bindgen(js_name = "isFinite")
+    )]
     pub fn is_finite(&self) -> bool {
         !matches!(self.classify(), FpCategory::Infinite | FpCategory::Nan)
     }
 
-    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = "isZero"))]
+    #[cfg_attr(
+        all(target_arch = "wasm32", target_os = "unknown"),
+        wasm_bindgen(js_name = "isZero")
+    )]
     pub fn is_zero(&self) -> bool {
         self.classify() == FpCategory::Zero
     }
 
-    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = "isSubnormal"))]
+    #[cfg_attr(
+        all(target_arch = "wasm32", target_os = "unknown"),
+        wasm_bindgen(js_name = "isSubnormal")
+    )]
     pub fn is_subnormal(&self) -> bool {
         self.classify() == FpCategory::Subnormal
     }
 
-    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = "isNormal"))]
+    #[cfg_attr(
+        all(target_arch = "wasm32", target_os = "unknown"),
+        wasm_bindgen(js_name = "isNormal")
+    )]
     pub fn is_normal(&self) -> bool {
         self.classify() == FpCategory::Normal
     }
 
-    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = "isReal"))]
+    #[cfg_attr(
+        all(target_arch = "wasm32", target_os = "unknown"),
+        wasm_bindgen(js_name = "isReal")
+    )]
     pub fn is_real(&self) -> bool {
         self.tan.classify() == FpCategory::Zero && self.mag.classify() != FpCategory::Nan
     }
 
-    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = "isImaginary"))]
+    #[cfg_attr(
+        all(target_arch = "wasm32", target_os = "unknown"),
+        wasm_bindgen(js_name = "isImaginary")
+    )]
     pub fn is_imaginary(&self) -> bool {
         self.tan.classify() == FpCategory::Infinite && self.mag.classify() != FpCategory::Nan
     }
 }
 
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[wasm_bindgen]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Category {
+    Nan = "nan",
+    Infinite = "infinite",
+    Zero = "zero",
+    Subnormal = "subnormal",
+    Normal = "normal",
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+impl From<FpCategory> for Category {
+    fn from(c: FpCategory) -> Self {
+        match c {
+            FpCategory::Nan => Category::Nan,
+            FpCategory::Infinite => Category::Infinite,
+            FpCategory::Zero => Category::Zero,
+            FpCategory::Subnormal => Category::Subnormal,
+            FpCategory::Normal => Category::Normal,
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[wasm_bindgen]
+impl Phasor {
+    #[wasm_bindgen(js_name = "classify")]
+    pub fn category(&self) -> Category {
+        self.classify().into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,153 @@
+use super::Phasor;
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen::prelude::*;
+
+impl Phasor {
+    pub const ZERO: Phasor = Phasor::raw(0f64, 0f64);
+    pub const ONE: Phasor = Phasor::raw(1f64, 0f64);
+    pub const I: Phasor = Phasor::raw(1f64, f64::INFINITY);
+    pub const NEG_ONE: Phasor = Phasor::raw(-1f64, -0f64);
+    pub const NEG_I: Phasor = Phasor::raw(-1f64, f64::INFINITY);
+    pub const INFINITY: Phasor = Phasor::raw(f64::INFINITY, 0f64);
+    pub const NAN: Phasor = Phasor::raw(f64::NAN, f64::NAN);
+
+    /// Builds a phasor from its magnitude and tangent as is.
+    pub const fn raw(mag: f64, tan: f64) -> Self {
+        Phasor { mag, tan }
+    }
+
+    /// Builds the phasor of magnitude `mag` rotated by `k` quarter turns, that is `mag·iᵏ`.
+    pub const fn quarter_turns(mag: f64, k: i32) -> Self {
+        match k.rem_euclid(4) {
+            0 => Phasor::raw(mag, 0f64),
+            1 => Phasor::raw(mag, f64::INFINITY),
+            2 => Phasor::raw(-mag, -0f64),
+            _ => Phasor::raw(-mag, f64::INFINITY),
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[wasm_bindgen]
+impl Phasor {
+    #[wasm_bindgen(getter = ZERO)]
+    pub fn zero() -> Phasor {
+        Phasor::ZERO
+    }
+
+    #[wasm_bindgen(getter = ONE)]
+    pub fn one() -> Phasor {
+        Phasor::ONE
+    }
+
+    #[wasm_bindgen(getter = I)]
+    pub fn imaginary_unit() -> Phasor {
+        Phasor::I
+    }
+
+    #[wasm_bindgen(getter = NEG_ONE)]
+    pub fn neg_one() -> Phasor {
+        Phasor::NEG_ONE
+    }
+
+    #[wasm_bindgen(getter = NEG_I)]
+    pub fn neg_imaginary_unit() -> Phasor {
+        Phasor::NEG_I
+    }
+
+    #[wasm_bindgen(getter = INFINITY)]
+    pub fn infinity() -> Phasor {
+        Phasor::INFINITY
+    }
+
+    #[wasm_bindgen(getter = NAN)]
+    pub fn nan() -> Phasor {
+        Phasor::NAN
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arbitrary::*;
+    use approx::{assert_relative_eq, assert_ulps_eq};
+    use std::f64::consts::FRAC_PI_2;
+    use test_strategy::proptest;
+
+    #[test]
+    fn constants_have_expected_values() {
+        assert_ulps_eq!(Phasor::ZERO, Phasor::rect(0f64, 0f64));
+        assert_ulps_eq!(Phasor::ONE, Phasor::rect(1f64, 0f64));
+        assert_ulps_eq!(Phasor::I, Phasor::rect(0f64, 1f64));
+        assert_ulps_eq!(Phasor::NEG_ONE, Phasor::rect(-1f64, 0f64));
+        assert_ulps_eq!(Phasor::NEG_I, Phasor::rect(0f64, -1f64));
+        assert!(Phasor::INFINITY.is_infinite() && Phasor::INFINITY.is_real());
+        assert!(Phasor::NAN.is_nan());
+    }
+
+    #[test]
+    fn constants_have_expected_angles() {
+        assert_eq!(Phasor::NEG_ONE.angle(), Phasor::rect(-1f64, 0f64).angle());
+        assert_eq!(
+            Phasor::quarter_turns(1f64, 2).angle(),
+            Phasor::NEG_ONE.angle()
+        );
+    }
+
+    #[test]
+    fn constants_are_canonical() {
+        for p in [
+            Phasor::ZERO,
+            Phasor::ONE,
+            Phasor::I,
+            Phasor::NEG_ONE,
+            Phasor::NEG_I,
+            Phasor::INFINITY,
+        ] {
+            assert_eq!(p.canonicalize(), p);
+        }
+    }
+
+    #[test]
+    fn constants_are_products_of_the_imaginary_unit() {
+        assert_eq!(Phasor::I * Phasor::I, Phasor::NEG_ONE);
+        assert_eq!(Phasor::NEG_ONE * Phasor::I, Phasor::NEG_I);
+        assert_eq!(Phasor::NEG_I * Phasor::I, Phasor::ONE);
+    }
+
+    #[proptest]
+    fn raw_preserves_magnitude_and_tangent(
+        #[strategy(any())] mag: f64,
+        #[strategy(any())] tan: f64,
+    ) {
+        let p = Phasor::raw(mag, tan);
+        assert_eq!(p.mag.to_bits(), mag.to_bits());
+        assert_eq!(p.tan.to_bits(), tan.to_bits());
+    }
+
+    #[proptest]
+    fn quarter_turns_rotates_by_right_angles(
+        #[strategy(normal())] mag: f64,
+        #[strategy(-100i32..100)] k: i32,
+    ) {
+        assert_relative_eq!(
+            Phasor::quarter_turns(mag, k),
+            Phasor::polar(mag, k as f64 * FRAC_PI_2),
+            epsilon = 1E-13
+        );
+    }
+
+    #[proptest]
+    fn quarter_turns_is_periodic(#[strategy(not_nan())] mag: f64, k: i32) {
+        let p = Phasor::quarter_turns(mag, k);
+        assert_eq!(p, Phasor::quarter_turns(mag, k.wrapping_add(4)));
+        assert_eq!(p, Phasor::quarter_turns(mag, k.rem_euclid(4)));
+    }
+
+    #[test]
+    fn constants_are_usable_in_const_context() {
+        const P: Phasor = Phasor::quarter_turns(2f64, -1);
+        assert_eq!(P, Phasor::raw(-2f64, f64::INFINITY));
+    }
+}
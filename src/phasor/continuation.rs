@@ -0,0 +1,163 @@
+use super::Phasor;
+use std::f64::consts::PI;
+
+/// The multi-valued functions whose branches [`Continuation`] can follow.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Multivalued {
+    Ln,
+    Sqrt,
+}
+
+impl Multivalued {
+    /// Evaluates the function at `p` on the `k`-th branch.
+    pub fn eval(self, p: Phasor, k: i32) -> Phasor {
+        match self {
+            Multivalued::Ln => p.ln_branch(k),
+            Multivalued::Sqrt => p.sqrt_branch(k),
+        }
+    }
+}
+
+/// Follows a [`Multivalued`] function along a path, switching branches whenever the path
+/// crosses the cut along the negative real axis, so that the values it yields vary
+/// continuously.
+///
+/// Consecutive points on the path are assumed to be less than half a turn apart as seen from
+/// the origin, as there is otherwise no telling which way around the origin the path went.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Continuation {
+    function: Multivalued,
+    branch: i32,
+    angle: Option<f64>,
+}
+
+impl Continuation {
+    /// Starts following `function` on the `k`-th branch.
+    pub fn new(function: Multivalued, k: i32) -> Self {
+        Continuation {
+            function,
+            branch: k,
+            angle: None,
+        }
+    }
+
+    /// The branch the last point on the path was evaluated on.
+    pub fn branch(&self) -> i32 {
+        self.branch
+    }
+
+    /// Advances to the next point on the path, returning the value of the function there.
+    pub fn step(&mut self, p: Phasor) -> Phasor {
+        let angle = p.angle();
+
+        if let Some(previous) = self.angle.filter(|_| !angle.is_nan()) {
+            if angle - previous < -PI {
+                self.branch += 1;
+            } else if angle - previous > PI {
+                self.branch -= 1;
+            }
+        }
+
+        if !angle.is_nan() {
+            self.angle = Some(angle);
+        }
+
+        self.function.eval(p, self.branch)
+    }
+
+    /// Evaluates the function at every point on the path.
+    pub fn along<I>(mut self, path: I) -> impl Iterator<Item = Phasor>
+    where
+        I: IntoIterator<Item = Phasor>,
+    {
+        path.into_iter().map(move |p| self.step(p))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::{assert_relative_eq, assert_ulps_eq};
+    use std::f64::consts::{FRAC_PI_2, TAU};
+    use test_strategy::proptest;
+
+    fn circle(r: f64, from: f64, turns: f64) -> impl Iterator<Item = Phasor> {
+        const STEPS: usize = 64;
+        let n = (STEPS as f64 * turns.abs()).ceil() as usize;
+        (0..=n).map(move |i| Phasor::polar(r, from + TAU * turns * i as f64 / n as f64))
+    }
+
+    #[proptest]
+    fn ln_is_continuous_around_the_origin(
+        #[strategy(1E-3f64..1E3)] r: f64,
+        #[strategy(-3f64..3f64)] from: f64,
+        #[strategy(-3f64..3f64)] turns: f64,
+    ) {
+        let values: Vec<_> = Continuation::new(Multivalued::Ln, 0)
+            .along(circle(r, from, turns))
+            .collect();
+
+        for w in values.windows(2) {
+            assert!((w[1] - w[0]).norm() < 0.2);
+        }
+
+        let (first, last) = (values[0], values[values.len() - 1]);
+        assert_ulps_eq!(last.real(), first.real(), epsilon = 1E-12);
+        assert_ulps_eq!(last.imag() - first.imag(), TAU * turns, epsilon = 1E-9);
+    }
+
+    #[test]
+    fn sqrt_changes_sign_after_one_turn() {
+        let mut c = Continuation::new(Multivalued::Sqrt, 0);
+        let values: Vec<_> = circle(4f64, 0f64, 1f64).map(|p| c.step(p)).collect();
+
+        assert_eq!(c.branch(), 1);
+        assert_relative_eq!(values[0], Phasor::rect(2f64, 0f64));
+        assert_relative_eq!(
+            values[values.len() - 1],
+            Phasor::rect(-2f64, 0f64),
+            epsilon = 1E-14
+        );
+
+        let values: Vec<_> = circle(4f64, 0f64, 1f64).map(|p| c.step(p)).collect();
+        assert_eq!(c.branch(), 2);
+        assert_relative_eq!(
+            values[values.len() - 1],
+            Phasor::rect(2f64, 0f64),
+            epsilon = 1E-14
+        );
+    }
+
+    #[test]
+    fn branch_is_unchanged_by_paths_that_do_not_enclose_the_origin() {
+        let mut c = Continuation::new(Multivalued::Ln, 3);
+
+        for p in circle(1f64, FRAC_PI_2, 1f64) {
+            c.step(p + Phasor::rect(-3f64, 0f64));
+        }
+
+        assert_eq!(c.branch(), 3);
+    }
+
+    #[test]
+    fn clockwise_turns_decrement_the_branch() {
+        let mut c = Continuation::new(Multivalued::Ln, 0);
+
+        for p in circle(1f64, 1f64, -2f64) {
+            c.step(p);
+        }
+
+        assert_eq!(c.branch(), -2);
+    }
+
+    #[test]
+    fn nan_points_are_skipped_when_tracking() {
+        let mut c = Continuation::new(Multivalued::Ln, 0);
+
+        c.step(Phasor::polar(1f64, 3f64));
+        assert!(c.step(Phasor::NAN).is_nan());
+        c.step(Phasor::polar(1f64, -3f64));
+
+        assert_eq!(c.branch(), 1);
+    }
+}
@@ -0,0 +1,148 @@
+use super::Phasor;
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen::prelude::*;
+
+impl Phasor {
+    /// Constructs the phasor of unit norm at the given angle, that is `cos(angle) + i·sin(angle)`.
+    pub fn cis(angle: f64) -> Self {
+        Phasor::polar(1f64, angle)
+    }
+
+    /// The real and imaginary parts.
+    pub fn to_rect(&self) -> (f64, f64) {
+        (self.real(), self.imag())
+    }
+
+    /// The norm and angle.
+    pub fn to_polar(&self) -> (f64, f64) {
+        (self.norm(), self.angle())
+    }
+}
+
+impl From<f64> for Phasor {
+    fn from(re: f64) -> Self {
+        Phasor::rect(re, 0f64)
+    }
+}
+
+impl From<(f64, f64)> for Phasor {
+    fn from((re, im): (f64, f64)) -> Self {
+        Phasor::rect(re, im)
+    }
+}
+
+impl From<Phasor> for (f64, f64) {
+    fn from(p: Phasor) -> Self {
+        p.to_rect()
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[wasm_bindgen]
+pub fn cis(angle: f64) -> Phasor {
+    Phasor::cis(angle)
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[wasm_bindgen]
+impl Phasor {
+    #[wasm_bindgen(js_name = "toRect")]
+    pub fn to_rect_array(&self) -> Vec<f64> {
+        let (re, im) = self.to_rect();
+        vec![re, im]
+    }
+
+    #[wasm_bindgen(js_name = "toPolar")]
+    pub fn to_polar_array(&self) -> Vec<f64> {
+        let (mag, ang) = self.to_polar();
+        vec![mag, ang]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arbitrary::{any, *};
+    use approx::assert_ulps_eq;
+    use test_strategy::proptest;
+
+    #[proptest]
+    fn cis_has_unit_norm(#[strategy(finite())] angle: f64) {
+        assert_ulps_eq!(Phasor::cis(angle).norm(), 1f64);
+    }
+
+    #[proptest]
+    fn cis_equals_cosine_plus_i_sine(#[strategy(-1E3f64..1E3)] angle: f64) {
+        let (re, im) = Phasor::cis(angle).to_rect();
+        assert_ulps_eq!(re, angle.cos(), epsilon = 1E-15);
+        assert_ulps_eq!(im, angle.sin(), epsilon = 1E-15);
+    }
+
+    #[proptest]
+    fn to_rect_equals_real_and_imaginary_parts(
+        #[strategy(any())] mag: f64,
+        #[strategy(any())] tan: f64,
+    ) {
+        let p = Phasor { mag, tan };
+        let (re, im) = p.to_rect();
+
+        assert_eq!(re.to_bits(), p.real().to_bits());
+        assert_eq!(im.to_bits(), p.imag().to_bits());
+    }
+
+    #[proptest]
+    fn to_polar_equals_norm_and_angle(#[strategy(any())] mag: f64, #[strategy(any())] tan: f64) {
+        let p = Phasor { mag, tan };
+        let (r, a) = p.to_polar();
+
+        assert_eq!(r.to_bits(), p.norm().to_bits());
+        assert_eq!(a.to_bits(), p.angle().to_bits());
+    }
+
+    #[proptest]
+    fn to_polar_is_inverse_of_polar(
+        #[strategy(regular())] mag: f64,
+        #[strategy(-3f64..3f64)] angle: f64,
+    ) {
+        let (r, a) = Phasor::polar(mag.abs(), angle).to_polar();
+
+        assert_ulps_eq!(r, mag.abs());
+        assert_ulps_eq!(a, angle, epsilon = 1E-15);
+    }
+
+    #[proptest]
+    fn from_real_is_real(#[strategy(not_nan())] re: f64) {
+        let p = Phasor::from(re);
+
+        assert!(p.is_real());
+        assert_eq!(p.real(), re);
+        assert_eq!(p, Phasor::rect(re, 0f64));
+    }
+
+    #[proptest]
+    fn from_pair_is_rect(#[strategy(any())] re: f64, #[strategy(any())] im: f64) {
+        let p: Phasor = (re, im).into();
+        let q = Phasor::rect(re, im);
+
+        assert_eq!(p.mag.to_bits(), q.mag.to_bits());
+        assert_eq!(p.tan.to_bits(), q.tan.to_bits());
+    }
+
+    #[proptest]
+    fn into_pair_is_to_rect(#[strategy(finite())] mag: f64, #[strategy(not_nan())] tan: f64) {
+        let p = Phasor { mag, tan };
+        assert_eq!(<(f64, f64)>::from(p), p.to_rect());
+    }
+
+    #[proptest]
+    fn rect_round_trips_through_pair(
+        #[strategy(-1E100f64..1E100)] re: f64,
+        #[strategy(-1E100f64..1E100)] im: f64,
+    ) {
+        let (x, y) = Phasor::from((re, im)).into();
+
+        assert_ulps_eq!(x, re, epsilon = 1E-15 * re.abs().max(im.abs()));
+        assert_ulps_eq!(y, im, epsilon = 1E-15 * re.abs().max(im.abs()));
+    }
+}
@@ -1,27 +1,302 @@
 use super::Phasor;
-use std::fmt::{Display, Error, Formatter, LowerExp, UpperExp};
+use std::fmt::{self, Alignment, Display, Error, Formatter, LowerExp, UpperExp, Write};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen::prelude::*;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Notation {
+    #[default]
+    Polar,
+    Rect,
+    Exp,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AngleUnit {
+    #[default]
+    Radians,
+    Degrees,
+}
+
+/// How to write a phasor, starting from [`Format::default`] and setting fields, since more may be
+/// added.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Format {
+    pub notation: Notation,
+    pub unit: AngleUnit,
+    pub precision: Option<usize>,
+    pub engineering: bool,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Formatted {
+    phasor: Phasor,
+    format: Format,
+}
+
+impl Phasor {
+    pub fn format(&self, format: Format) -> Formatted {
+        Formatted {
+            phasor: *self,
+            format,
+        }
+    }
+}
+
+const PREFIXES: [&str; 17] = [
+    "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+];
+
+pub(super) fn engineering(x: f64, precision: Option<usize>) -> (f64, &'static str) {
+    if !x.is_normal() {
+        return (x, "");
+    }
+
+    let round = |m: f64| match precision {
+        Some(p) if p < 16 => (m * 10f64.powi(p as i32)).round() / 10f64.powi(p as i32),
+        _ => m,
+    };
+
+    let mut e = (x.abs().log10().floor() as i32).div_euclid(3).clamp(-8, 8);
+    let mut m = round(x / 10f64.powi(3 * e));
+
+    if m.abs() >= 1000f64 && e < 8 {
+        e += 1;
+        m = round(x / 10f64.powi(3 * e));
+    } else if m.abs() < 1f64 && e > -8 {
+        e -= 1;
+        m = round(x / 10f64.powi(3 * e));
+    }
+
+    (m, PREFIXES[(e + 8) as usize])
+}
+
+fn number(w: &mut impl Write, x: f64, fmt: &Format) -> fmt::Result {
+    let (x, prefix) = if fmt.engineering {
+        engineering(x, fmt.precision)
+    } else {
+        (x, "")
+    };
+
+    match fmt.precision {
+        Some(p) => write!(w, "{x:.p$}{prefix}"),
+        None => write!(w, "{x}{prefix}"),
+    }
+}
+
+impl Display for Formatted {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let Formatted { phasor: p, format } = self;
+        let mut s = String::new();
+
+        match format.notation {
+            Notation::Polar => {
+                number(&mut s, p.norm(), format)?;
+                s.push('∠');
+
+                match format.unit {
+                    AngleUnit::Radians => match format.precision {
+                        Some(n) => write!(s, "{:.n$}", p.angle())?,
+                        None => write!(s, "{}", p.angle())?,
+                    },
+
+                    AngleUnit::Degrees => match format.precision {
+                        Some(n) => write!(s, "{:.n$}°", p.angle().to_degrees())?,
+                        None => write!(s, "{}°", p.angle().to_degrees())?,
+                    },
+                }
+            }
+
+            Notation::Rect => {
+                let (re, im) = (p.real(), p.imag());
+                number(&mut s, re, format)?;
+                s.push(if im.is_sign_negative() && !im.is_nan() {
+                    '-'
+                } else {
+                    '+'
+                });
+                number(&mut s, im.abs(), format)?;
+                s.push('i');
+            }
+
+            Notation::Exp => {
+                let angle = p.angle();
+                number(&mut s, p.norm(), format)?;
+                s.push_str(if angle.is_sign_negative() && !angle.is_nan() {
+                    "e^-i"
+                } else {
+                    "e^i"
+                });
+
+                match format.precision {
+                    Some(n) => write!(s, "{:.n$}", angle.abs())?,
+                    None => write!(s, "{}", angle.abs())?,
+                }
+            }
+        }
+
+        let padding = f.width().unwrap_or(0).saturating_sub(s.chars().count());
+        let (before, after) = match f.align() {
+            Some(Alignment::Left) => (0, padding),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(Alignment::Right) | None => (padding, 0),
+        };
+
+        for _ in 0..before {
+            f.write_char(f.fill())?;
+        }
+
+        f.write_str(&s)?;
+
+        for _ in 0..after {
+            f.write_char(f.fill())?;
+        }
+
+        Ok(())
+    }
+}
 
 impl Display for Phasor {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{}∠{}", self.norm(), self.angle())
+        let format = Format {
+            notation: if f.alternate() {
+                Notation::Rect
+            } else {
+                Notation::Polar
+            },
+            precision: f.precision(),
+            ..Format::default()
+        };
+
+        Display::fmt(&self.format(format), f)
     }
 }
 
 impl LowerExp for Phasor {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:e}∠{:e}", self.norm(), self.angle())
+        match f.precision() {
+            Some(n) => write!(f, "{:.n$e}∠{:.n$e}", self.norm(), self.angle()),
+            None => write!(f, "{:e}∠{:e}", self.norm(), self.angle()),
+        }
     }
 }
 
 impl UpperExp for Phasor {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:E}∠{:E}", self.norm(), self.angle())
+        match f.precision() {
+            Some(n) => write!(f, "{:.n$E}∠{:.n$E}", self.norm(), self.angle()),
+            None => write!(f, "{:E}∠{:E}", self.norm(), self.angle()),
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[wasm_bindgen(typescript_custom_section)]
+const FORMAT_OPTIONS: &str = r#"
+export interface FormatOptions {
+    notation?: "polar" | "rect" | "exp";
+    unit?: "rad" | "deg";
+    precision?: number;
+    engineering?: boolean;
+}
+"#;
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "FormatOptions")]
+    pub type FormatOptions;
+
+    #[wasm_bindgen(method, getter)]
+    fn notation(this: &FormatOptions) -> Option<String>;
+
+    #[wasm_bindgen(method, getter)]
+    fn unit(this: &FormatOptions) -> Option<String>;
+
+    #[wasm_bindgen(method, getter)]
+    fn precision(this: &FormatOptions) -> Option<f64>;
+
+    #[wasm_bindgen(method, getter)]
+    fn engineering(this: &FormatOptions) -> Option<bool>;
+}
+
+/// A number of digits from JavaScript, which must be a whole number between 0 and 100 like for
+/// `Number.prototype.toFixed`.
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+fn fraction_digits(n: f64) -> Option<usize> {
+    ((0f64..=100f64).contains(&n) && n.fract() == 0f64).then_some(n as usize)
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+impl TryFrom<&FormatOptions> for Format {
+    type Error = JsError;
+
+    fn try_from(options: &FormatOptions) -> Result<Self, Self::Error> {
+        Ok(Format {
+            notation: match options.notation().as_deref() {
+                None | Some("polar") => Notation::Polar,
+                Some("rect") => Notation::Rect,
+                Some("exp") => Notation::Exp,
+                Some(n) => return Err(JsError::new(&format!("invalid notation `{n}`"))),
+            },
+
+            unit: match options.unit().as_deref() {
+                None | Some("rad") => AngleUnit::Radians,
+                Some("deg") => AngleUnit::Degrees,
+                Some(u) => return Err(JsError::new(&format!("invalid unit `{u}`"))),
+            },
+
+            precision: match options.precision() {
+                None => None,
+                Some(p) => match fraction_digits(p) {
+                    Some(p) => Some(p),
+                    None => return Err(JsError::new(&format!("invalid precision `{p}`"))),
+                },
+            },
+
+            engineering: options.engineering().unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[wasm_bindgen]
+impl Phasor {
+    #[wasm_bindgen(js_name = "format")]
+    pub fn format_with(&self, options: Option<FormatOptions>) -> Result<String, JsError> {
+        let format = match &options {
+            Some(o) => Format::try_from(o)?,
+            None => Format::default(),
+        };
+
+        Ok(self.format(format).to_string())
+    }
+
+    #[wasm_bindgen(js_name = "toString")]
+    pub fn to_string_with(&self, options: Option<FormatOptions>) -> Result<String, JsError> {
+        self.format_with(options)
+    }
+
+    #[wasm_bindgen(js_name = "toExponential")]
+    pub fn to_exponential_with(&self, digits: Option<f64>) -> Result<String, JsError> {
+        match digits {
+            None => Ok(format!("{self:e}")),
+            Some(n) => match fraction_digits(n) {
+                Some(n) => Ok(format!("{self:.0$e}", n)),
+                None => Err(JsError::new(&format!("invalid number of digits `{n}`"))),
+            },
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::arbitrary::*;
     use test_strategy::proptest;
 
     #[proptest]
@@ -44,4 +319,146 @@ mod tests {
         assert!(format!("{p:e}").contains('∠'));
         assert!(format!("{p:E}").contains('∠'));
     }
+
+    #[proptest]
+    fn applies_precision_to_norm_and_angle(p: Phasor, #[strategy(0usize..20)] n: usize) {
+        assert_eq!(
+            format!("{p:.n$}"),
+            format!("{:.n$}∠{:.n$}", p.norm(), p.angle())
+        );
+    }
+
+    #[proptest]
+    fn applies_precision_in_exponential_notation(p: Phasor, #[strategy(0usize..20)] n: usize) {
+        assert_eq!(
+            format!("{p:.n$e}"),
+            format!("{:.n$e}∠{:.n$e}", p.norm(), p.angle())
+        );
+        assert_eq!(
+            format!("{p:.n$E}"),
+            format!("{:.n$E}∠{:.n$E}", p.norm(), p.angle())
+        );
+    }
+
+    #[proptest]
+    fn pads_to_width(p: Phasor, #[strategy(0usize..80)] w: usize) {
+        let s = format!("{p}");
+        let n = s.chars().count();
+
+        assert_eq!(format!("{p:w$}"), format!("{s:>w$}"));
+        assert_eq!(format!("{p:<w$}"), format!("{s:<w$}"));
+        assert_eq!(format!("{p:^w$}"), format!("{s:^w$}"));
+        assert_eq!(format!("{p:*>w$}").chars().count(), n.max(w));
+    }
+
+    #[proptest]
+    fn alternate_is_rectangular(#[strategy(finite())] re: f64, #[strategy(finite())] im: f64) {
+        let p = Phasor::rect(re, im);
+        let (re, im) = (p.real(), p.imag());
+
+        if im.is_sign_negative() {
+            assert_eq!(format!("{p:#}"), format!("{re}-{}i", -im));
+        } else {
+            assert_eq!(format!("{p:#}"), format!("{re}+{im}i"));
+        }
+    }
+
+    #[proptest]
+    fn alternate_applies_precision_to_real_and_imaginary_parts(
+        #[strategy(finite())] re: f64,
+        #[strategy(finite())] im: f64,
+        #[strategy(0usize..20)] n: usize,
+    ) {
+        let p = Phasor::rect(re, im);
+        let (re, im) = (p.real(), p.imag());
+        let sign = if im.is_sign_negative() { '-' } else { '+' };
+
+        assert_eq!(
+            format!("{p:#.n$}"),
+            format!("{re:.n$}{sign}{:.n$}i", im.abs())
+        );
+    }
+
+    #[proptest]
+    fn exponential_has_the_norm_as_coefficient_and_the_angle_as_exponent(p: Phasor) {
+        let format = Format {
+            notation: Notation::Exp,
+            ..Format::default()
+        };
+
+        let s = p.format(format).to_string();
+        let angle = p.angle();
+
+        assert!(s.starts_with(&format!("{}e^", p.norm())));
+
+        if angle.is_sign_negative() && !angle.is_nan() {
+            assert!(s.ends_with(&format!("e^-i{}", -angle)));
+        } else {
+            assert!(s.ends_with(&format!("e^i{angle}")));
+        }
+    }
+
+    #[proptest]
+    fn degrees_end_with_the_degree_symbol(p: Phasor) {
+        let format = Format {
+            unit: AngleUnit::Degrees,
+            ..Format::default()
+        };
+
+        let s = p.format(format).to_string();
+        assert!(s.ends_with(&format!("{}°", p.angle().to_degrees())));
+    }
+
+    #[proptest]
+    fn engineering_mantissa_is_within_one_and_a_thousand(#[strategy(1E-24f64..1E27)] x: f64) {
+        let (m, _) = engineering(x, None);
+        assert!((1f64..1000f64).contains(&m));
+    }
+
+    #[test]
+    fn engineering_uses_si_prefixes() {
+        let format = Format {
+            unit: AngleUnit::Degrees,
+            precision: Some(1),
+            engineering: true,
+            ..Format::default()
+        };
+
+        let p = Phasor::polar(1200f64, -30f64.to_radians());
+        assert_eq!(p.format(format).to_string(), "1.2k∠-30.0°");
+
+        let p = Phasor::polar(4.7E-6, 0f64);
+        assert_eq!(p.format(format).to_string(), "4.7µ∠0.0°");
+
+        let p = Phasor::polar(999.96, 0f64);
+        assert_eq!(p.format(format).to_string(), "1.0k∠0.0°");
+
+        let format = Format {
+            notation: Notation::Rect,
+            ..format
+        };
+
+        let p = Phasor::rect(3000f64, -4E6);
+        assert_eq!(p.format(format).to_string(), "3.0k-4.0Mi");
+    }
+
+    #[test]
+    fn engineering_leaves_special_values_untouched() {
+        let format = Format {
+            engineering: true,
+            ..Format::default()
+        };
+
+        assert_eq!(Phasor::polar(0f64, 0f64).format(format).to_string(), "0∠0");
+        assert_eq!(
+            Phasor::polar(f64::INFINITY, 0f64)
+                .format(format)
+                .to_string(),
+            "inf∠0"
+        );
+        assert_eq!(
+            Phasor::polar(f64::NAN, 0f64).format(format).to_string(),
+            "NaN∠NaN"
+        );
+    }
 }
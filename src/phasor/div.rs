@@ -2,7 +2,7 @@ use super::Phasor;
 use crate::trig::tansubatan;
 use std::ops::Div;
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 use wasm_bindgen::prelude::*;
 
 impl Div for Phasor {
@@ -18,7 +18,7 @@ impl Div for Phasor {
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 #[wasm_bindgen]
 impl Phasor {
     pub fn div(&self, rhs: &Phasor) -> Phasor {
@@ -0,0 +1,189 @@
+use super::{NotNan, Phasor};
+use std::ops::{Add, Deref, Div, Mul, Neg, Sub};
+
+/// A [`Phasor`] that is guaranteed to be neither _NaN_ nor infinite.
+///
+/// Operations that are closed over finite phasors return another [`Finite`], whereas those
+/// that may overflow or divide by zero return an [`Option`].
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Finite<T>(T);
+
+impl Finite<Phasor> {
+    pub fn new(p: Phasor) -> Option<Self> {
+        if p.is_finite() {
+            Some(Finite(p))
+        } else {
+            None
+        }
+    }
+
+    pub fn into_inner(self) -> Phasor {
+        self.0
+    }
+
+    pub fn conj(self) -> Self {
+        Finite(self.0.conj())
+    }
+
+    pub fn recip(self) -> Option<Self> {
+        Self::new(self.0.recip())
+    }
+
+    pub fn ln(self) -> Option<Self> {
+        Self::new(self.0.ln())
+    }
+
+    pub fn exp(self) -> Option<Self> {
+        Self::new(self.0.exp())
+    }
+
+    pub fn log(self, base: f64) -> Option<Self> {
+        Self::new(self.0.log(base))
+    }
+
+    pub fn sinh(self) -> Option<Self> {
+        Self::new(self.0.sinh())
+    }
+
+    pub fn cosh(self) -> Option<Self> {
+        Self::new(self.0.cosh())
+    }
+}
+
+impl Eq for Finite<Phasor> {}
+
+impl Deref for Finite<Phasor> {
+    type Target = Phasor;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Finite<Phasor>> for Phasor {
+    fn from(p: Finite<Phasor>) -> Self {
+        p.0
+    }
+}
+
+impl From<Finite<Phasor>> for NotNan<Phasor> {
+    fn from(p: Finite<Phasor>) -> Self {
+        NotNan::new(p.0).unwrap()
+    }
+}
+
+impl Neg for Finite<Phasor> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Finite(-self.0)
+    }
+}
+
+impl Add for Finite<Phasor> {
+    type Output = Option<Self>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Finite<Phasor> {
+    type Output = Option<Self>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.0 - rhs.0)
+    }
+}
+
+impl Mul for Finite<Phasor> {
+    type Output = Option<Self>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.0 * rhs.0)
+    }
+}
+
+impl Div for Finite<Phasor> {
+    type Output = Option<Self>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new(self.0 / rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arbitrary::{any, *};
+    use test_strategy::proptest;
+
+    #[proptest]
+    fn rejects_nan(#[strategy(any())] mag: f64, #[strategy(nan())] tan: f64) {
+        assert_eq!(Finite::new(Phasor { mag, tan }), None);
+        assert_eq!(Finite::new(Phasor { mag: tan, tan: mag }), None);
+    }
+
+    #[proptest]
+    fn rejects_infinity(#[strategy(infinite())] mag: f64, #[strategy(not_nan())] tan: f64) {
+        assert_eq!(Finite::new(Phasor { mag, tan }), None);
+    }
+
+    #[proptest]
+    fn accepts_finite(#[strategy(finite())] mag: f64, #[strategy(not_nan())] tan: f64) {
+        let p = Phasor { mag, tan };
+        assert_eq!(Finite::new(p).map(Phasor::from), Some(p));
+        assert_eq!(Finite::new(p).map(NotNan::from), NotNan::new(p));
+    }
+
+    #[proptest]
+    fn is_reflexive(#[strategy(finite())] mag: f64, #[strategy(not_nan())] tan: f64) {
+        let p = Finite::new(Phasor { mag, tan }).unwrap();
+        assert_eq!(p, p);
+    }
+
+    #[proptest]
+    fn total_operations_are_equivalent_to_those_of_phasor(
+        #[strategy(finite())] mag: f64,
+        #[strategy(not_nan())] tan: f64,
+    ) {
+        let p = Phasor { mag, tan };
+        let q = Finite::new(p).unwrap();
+
+        assert_eq!(*(-q), -p);
+        assert_eq!(*q.conj(), p.conj());
+    }
+
+    #[proptest]
+    fn fallible_operations_are_some_only_if_finite(
+        #[strategy(finite())] a: f64,
+        #[strategy(not_nan())] b: f64,
+        #[strategy(finite())] c: f64,
+        #[strategy(not_nan())] d: f64,
+    ) {
+        let (p, q) = (Phasor { mag: a, tan: b }, Phasor { mag: c, tan: d });
+        let (u, v) = (Finite::new(p).unwrap(), Finite::new(q).unwrap());
+
+        assert_eq!(u + v, Finite::new(p + q));
+        assert_eq!(u - v, Finite::new(p - q));
+        assert_eq!(u * v, Finite::new(p * q));
+        assert_eq!(u / v, Finite::new(p / q));
+        assert_eq!(u.recip(), Finite::new(p.recip()));
+        assert_eq!(u.ln(), Finite::new(p.ln()));
+        assert_eq!(u.exp(), Finite::new(p.exp()));
+    }
+
+    #[proptest]
+    fn division_by_zero_is_none(
+        #[strategy(finite())] a: f64,
+        #[strategy(not_nan())] b: f64,
+        #[strategy(zero())] c: f64,
+        #[strategy(not_nan())] d: f64,
+    ) {
+        let p = Finite::new(Phasor { mag: a, tan: b }).unwrap();
+        let q = Finite::new(Phasor { mag: c, tan: d }).unwrap();
+
+        assert_eq!(p / q, None);
+        assert_eq!(q.recip(), None);
+    }
+}
@@ -0,0 +1,144 @@
+use super::Phasor;
+use js_sys::{Array, Object, Reflect, SyntaxError, TypeError};
+use std::fmt::{self, Display, Formatter};
+use wasm_bindgen::{prelude::*, JsCast};
+
+#[wasm_bindgen(typescript_custom_section)]
+const PHASOR_JSON: &str = r#"
+export type PhasorJSONNumber = number | "Infinity" | "-Infinity" | "NaN";
+
+export interface PhasorJSON {
+    mag: PhasorJSONNumber;
+    tan: PhasorJSONNumber;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "PhasorJSON")]
+    pub type PhasorJson;
+}
+
+/// The reason why a JSON value doesn't describe a phasor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonError {
+    /// The value is not an object.
+    NotAnObject,
+    /// The object lacks one of the fields.
+    MissingField(&'static str),
+    /// A field is neither a number nor a string.
+    InvalidType(&'static str),
+    /// A field is a string other than those that encode non-finite numbers.
+    InvalidNumber(&'static str, String),
+}
+
+impl Display for JsonError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonError::NotAnObject => write!(f, "expected an object with fields `mag` and `tan`"),
+            JsonError::MissingField(field) => write!(f, "missing field `{field}`"),
+            JsonError::InvalidType(field) => write!(
+                f,
+                "invalid type of field `{field}`, expected a number or one of \
+                 \"Infinity\", \"-Infinity\" and \"NaN\""
+            ),
+            JsonError::InvalidNumber(field, s) => {
+                write!(f, "invalid number `{s}` in field `{field}`")
+            }
+        }
+    }
+}
+
+impl From<JsonError> for JsValue {
+    fn from(e: JsonError) -> Self {
+        match e {
+            JsonError::InvalidNumber(..) => SyntaxError::new(&e.to_string()).into(),
+            _ => TypeError::new(&e.to_string()).into(),
+        }
+    }
+}
+
+/// Encodes non-finite numbers as strings, which `JSON.stringify` would otherwise turn into `null`.
+fn encode(x: f64) -> JsValue {
+    if x.is_nan() {
+        JsValue::from_str("NaN")
+    } else if x == f64::INFINITY {
+        JsValue::from_str("Infinity")
+    } else if x == f64::NEG_INFINITY {
+        JsValue::from_str("-Infinity")
+    } else {
+        JsValue::from_f64(x)
+    }
+}
+
+fn decode(json: &JsValue, field: &'static str) -> Result<f64, JsonError> {
+    let value = Reflect::get(json, &JsValue::from_str(field)).unwrap_or(JsValue::UNDEFINED);
+
+    if let Some(x) = value.as_f64() {
+        return Ok(x);
+    }
+
+    match value.as_string().as_deref() {
+        Some("NaN") => Ok(f64::NAN),
+        Some("Infinity") => Ok(f64::INFINITY),
+        Some("-Infinity") => Ok(f64::NEG_INFINITY),
+        Some(s) => Err(JsonError::InvalidNumber(field, s.into())),
+        None if value.is_undefined() => Err(JsonError::MissingField(field)),
+        None => Err(JsonError::InvalidType(field)),
+    }
+}
+
+impl TryFrom<&JsValue> for Phasor {
+    type Error = JsonError;
+
+    fn try_from(json: &JsValue) -> Result<Self, Self::Error> {
+        if !json.is_object() {
+            return Err(JsonError::NotAnObject);
+        }
+
+        Ok(Phasor {
+            mag: decode(json, "mag")?,
+            tan: decode(json, "tan")?,
+        })
+    }
+}
+
+/// Whether a value has exactly the fields `mag` and `tan`, like the output of `toJSON`.
+fn is_phasor_json(value: &JsValue) -> bool {
+    if !value.is_object() || Array::is_array(value) {
+        return false;
+    }
+
+    let keys = Object::keys(value.unchecked_ref::<Object>());
+    keys.length() == 2
+        && keys.includes(&JsValue::from_str("mag"), 0)
+        && keys.includes(&JsValue::from_str("tan"), 0)
+}
+
+#[wasm_bindgen]
+impl Phasor {
+    /// Encodes a phasor as a plain object for `JSON.stringify`, without losing non-finite fields.
+    #[wasm_bindgen(js_name = "toJSON")]
+    pub fn to_json(&self) -> PhasorJson {
+        let json = Object::new();
+        Reflect::set(&json, &JsValue::from_str("mag"), &encode(self.mag)).unwrap_throw();
+        Reflect::set(&json, &JsValue::from_str("tan"), &encode(self.tan)).unwrap_throw();
+        json.unchecked_into()
+    }
+
+    /// Reconstructs a phasor from the plain object produced by `JSON.parse`.
+    #[wasm_bindgen(js_name = "fromJSON")]
+    pub fn from_json(json: &PhasorJson) -> Result<Phasor, JsValue> {
+        Ok(Phasor::try_from(json.as_ref())?)
+    }
+
+    /// A reviver for `JSON.parse`, which reconstructs every object that has exactly the fields
+    /// `mag` and `tan`.
+    pub fn reviver(_key: &str, value: JsValue) -> Result<JsValue, JsValue> {
+        if is_phasor_json(&value) {
+            Ok(Phasor::try_from(&value)?.into())
+        } else {
+            Ok(value)
+        }
+    }
+}
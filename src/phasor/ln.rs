@@ -1,13 +1,27 @@
 use super::Phasor;
+use std::f64::consts::TAU;
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 use wasm_bindgen::prelude::*;
 
-#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), wasm_bindgen)]
 impl Phasor {
     pub fn ln(self) -> Self {
         Phasor::rect(self.norm().ln(), self.angle())
     }
+
+    /// The natural logarithm on the `k`-th branch, whose imaginary part is offset by `2πk` from
+    /// that of the principal value.
+    #[cfg_attr(
+        all(target_arch = "wasm32", target_os = "unknown"),
+        wasm_bindgen(js_name = "lnBranch")
+    )]
+    pub fn ln_branch(self, k: i32) -> Self {
+        match k {
+            0 => self.ln(),
+            k => Phasor::rect(self.norm().ln(), self.angle() + TAU * k as f64),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -26,6 +40,31 @@ mod tests {
         assert_ulps_eq!(p.ln().real(), p.norm().ln());
     }
 
+    #[proptest]
+    fn principal_branch_is_ln(#[strategy(any())] mag: f64, #[strategy(any())] tan: f64) {
+        let p = Phasor { mag, tan };
+        let (q, r) = (p.ln_branch(0), p.ln());
+
+        assert_eq!(q.mag.to_bits(), r.mag.to_bits());
+        assert_eq!(q.tan.to_bits(), r.tan.to_bits());
+    }
+
+    #[proptest]
+    fn branches_differ_by_multiples_of_two_pi(
+        #[strategy(regular())] mag: f64,
+        #[strategy(not_nan())] tan: f64,
+        #[strategy(-100i32..100)] k: i32,
+    ) {
+        let p = Phasor { mag, tan };
+
+        assert_ulps_eq!(p.ln_branch(k).real(), p.ln().real());
+        assert_ulps_eq!(
+            p.ln_branch(k).imag(),
+            p.ln().imag() + TAU * k as f64,
+            epsilon = 1E-12
+        );
+    }
+
     #[proptest]
     fn has_imaginary_part_equal_to_angle(
         #[strategy(regular())] mag: f64,
@@ -0,0 +1,333 @@
+use super::{display::engineering, AngleUnit, Format, Notation, Phasor};
+use std::fmt::Write;
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use super::display::FormatOptions;
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen::prelude::*;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Markup {
+    Latex,
+    MathMl,
+}
+
+impl Markup {
+    fn nan(self, s: &mut String) {
+        match self {
+            Markup::Latex => s.push_str(r"\mathrm{NaN}"),
+            Markup::MathMl => s.push_str("<mi>NaN</mi>"),
+        }
+    }
+
+    fn op(self, s: &mut String, latex: &str, mathml: &str) {
+        match self {
+            Markup::Latex => s.push_str(latex),
+            Markup::MathMl => write!(s, "<mo>{mathml}</mo>").unwrap(),
+        }
+    }
+
+    fn number(self, s: &mut String, x: f64, format: &Format) {
+        if x.is_nan() {
+            return self.nan(s);
+        }
+
+        if x.is_sign_negative() {
+            self.op(s, "-", "-");
+        }
+
+        if x.is_infinite() {
+            return match self {
+                Markup::Latex => s.push_str(r"\infty"),
+                Markup::MathMl => s.push_str("<mi>∞</mi>"),
+            };
+        }
+
+        let (x, prefix) = if format.engineering {
+            engineering(x.abs(), format.precision)
+        } else {
+            (x.abs(), "")
+        };
+
+        let digits = match format.precision {
+            Some(p) => format!("{x:.p$}"),
+            None => format!("{x}"),
+        };
+
+        match self {
+            Markup::Latex => s.push_str(&digits),
+            Markup::MathMl => write!(s, "<mn>{digits}</mn>").unwrap(),
+        }
+
+        match (self, prefix) {
+            (_, "") => {}
+            (Markup::Latex, "µ") => s.push_str(r"\,\mu"),
+            (Markup::Latex, p) => write!(s, r"\,\mathrm{{{p}}}").unwrap(),
+            (Markup::MathMl, p) => write!(s, r#"<mi mathvariant="normal">{p}</mi>"#).unwrap(),
+        }
+    }
+
+    fn angle(self, s: &mut String, angle: f64, format: &Format) {
+        let radians = Format {
+            engineering: false,
+            ..*format
+        };
+
+        match format.unit {
+            AngleUnit::Radians => self.number(s, angle, &radians),
+            AngleUnit::Degrees => {
+                self.number(s, angle.to_degrees(), &radians);
+                match self {
+                    Markup::Latex => s.push_str(r"^\circ"),
+                    Markup::MathMl => s.push_str("<mo>°</mo>"),
+                }
+            }
+        }
+    }
+
+    fn render(self, p: &Phasor, format: &Format) -> String {
+        let mut s = String::new();
+
+        if p.is_nan() {
+            self.nan(&mut s);
+        } else {
+            match format.notation {
+                Notation::Polar => {
+                    self.number(&mut s, p.norm(), format);
+                    self.op(&mut s, r"\angle ", "∠");
+                    self.angle(&mut s, p.angle(), format);
+                }
+
+                Notation::Rect => {
+                    let (re, im) = (p.real(), p.imag());
+                    self.number(&mut s, re, format);
+                    let sign = if im.is_sign_negative() { "-" } else { "+" };
+                    self.op(&mut s, sign, sign);
+                    self.number(&mut s, im.abs(), format);
+
+                    match self {
+                        Markup::Latex => s.push('j'),
+                        Markup::MathMl => s.push_str("<mi>j</mi>"),
+                    }
+                }
+
+                Notation::Exp => {
+                    let radians = Format {
+                        unit: AngleUnit::Radians,
+                        ..*format
+                    };
+
+                    let angle = p.angle();
+                    let sign = if angle.is_sign_negative() { "-" } else { "" };
+
+                    let mut exponent = String::new();
+                    self.angle(&mut exponent, angle.abs(), &radians);
+                    self.number(&mut s, p.norm(), format);
+
+                    match self {
+                        Markup::Latex => write!(s, "e^{{{sign}j{exponent}}}").unwrap(),
+                        Markup::MathMl => {
+                            s.push_str("<msup><mi>e</mi><mrow>");
+
+                            if !sign.is_empty() {
+                                self.op(&mut s, sign, sign);
+                            }
+
+                            write!(s, "<mi>j</mi>{exponent}</mrow></msup>").unwrap();
+                        }
+                    }
+                }
+            }
+        }
+
+        match self {
+            Markup::Latex => s,
+            Markup::MathMl => {
+                format!(r#"<math xmlns="http://www.w3.org/1998/Math/MathML">{s}</math>"#)
+            }
+        }
+    }
+}
+
+impl Phasor {
+    /// Renders as a LaTeX formula, writing the imaginary unit as `j` as is customary in electrical
+    /// engineering, and always in radians in exponential notation, whatever the [`AngleUnit`].
+    pub fn to_latex(&self, format: Format) -> String {
+        Markup::Latex.render(self, &format)
+    }
+
+    /// Renders as a MathML `<math>` element, following the conventions of [`Phasor::to_latex`].
+    pub fn to_mathml(&self, format: Format) -> String {
+        Markup::MathMl.render(self, &format)
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[wasm_bindgen]
+impl Phasor {
+    #[wasm_bindgen(js_name = "toLatex")]
+    pub fn to_latex_with(&self, options: Option<FormatOptions>) -> Result<String, JsError> {
+        let format = match &options {
+            Some(o) => Format::try_from(o)?,
+            None => Format::default(),
+        };
+
+        Ok(self.to_latex(format))
+    }
+
+    #[wasm_bindgen(js_name = "toMathML")]
+    pub fn to_mathml_with(&self, options: Option<FormatOptions>) -> Result<String, JsError> {
+        let format = match &options {
+            Some(o) => Format::try_from(o)?,
+            None => Format::default(),
+        };
+
+        Ok(self.to_mathml(format))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arbitrary::*;
+    use test_strategy::proptest;
+
+    const POLAR: Format = Format {
+        notation: Notation::Polar,
+        unit: AngleUnit::Degrees,
+        precision: Some(1),
+        engineering: false,
+    };
+
+    const RECT: Format = Format {
+        notation: Notation::Rect,
+        unit: AngleUnit::Radians,
+        precision: None,
+        engineering: false,
+    };
+
+    const EXP: Format = Format {
+        notation: Notation::Exp,
+        unit: AngleUnit::Radians,
+        precision: Some(2),
+        engineering: false,
+    };
+
+    #[test]
+    fn latex_renders_polar_notation() {
+        let p = Phasor::rect(3f64, 4f64);
+        assert_eq!(p.to_latex(POLAR), r"5.0\angle 53.1^\circ");
+        assert_eq!(p.conj().to_latex(POLAR), r"5.0\angle -53.1^\circ");
+    }
+
+    #[test]
+    fn latex_renders_rectangular_notation() {
+        let p = Phasor::rect(3f64, 4f64);
+        assert_eq!(p.to_latex(RECT), "3+4j");
+        assert_eq!(p.conj().to_latex(RECT), "3-4j");
+    }
+
+    #[test]
+    fn latex_renders_exponential_notation() {
+        let p = Phasor::rect(3f64, 4f64);
+        assert_eq!(p.to_latex(EXP), "5.00e^{j0.93}");
+        assert_eq!(p.conj().to_latex(EXP), "5.00e^{-j0.93}");
+    }
+
+    #[test]
+    fn exponential_notation_takes_the_angle_in_radians() {
+        let p = Phasor::rect(3f64, 4f64);
+        let format = Format {
+            unit: AngleUnit::Degrees,
+            ..EXP
+        };
+
+        assert_eq!(p.to_latex(format), p.to_latex(EXP));
+        assert_eq!(p.to_mathml(format), p.to_mathml(EXP));
+    }
+
+    #[test]
+    fn latex_renders_engineering_notation() {
+        let format = Format {
+            engineering: true,
+            ..POLAR
+        };
+
+        let p = Phasor::polar(4.7E3, 0f64);
+        assert_eq!(p.to_latex(format), r"4.7\,\mathrm{k}\angle 0.0^\circ");
+
+        let p = Phasor::polar(4.7E-6, 0f64);
+        assert_eq!(p.to_latex(format), r"4.7\,\mu\angle 0.0^\circ");
+    }
+
+    #[test]
+    fn latex_renders_infinity() {
+        let p = Phasor::polar(f64::INFINITY, 0f64);
+        assert_eq!(p.to_latex(POLAR), r"\infty\angle 0.0^\circ");
+
+        let p = Phasor::rect(f64::NEG_INFINITY, 0f64);
+        assert_eq!(p.to_latex(RECT), r"-\infty+0j");
+    }
+
+    #[proptest]
+    fn latex_renders_nan_as_such(#[strategy(any())] mag: f64, #[strategy(nan())] tan: f64) {
+        let p = Phasor { mag, tan };
+        assert_eq!(p.to_latex(POLAR), r"\mathrm{NaN}");
+        assert_eq!(p.to_latex(RECT), r"\mathrm{NaN}");
+        assert_eq!(p.to_latex(EXP), r"\mathrm{NaN}");
+    }
+
+    #[test]
+    fn mathml_renders_polar_notation() {
+        let p = Phasor::rect(3f64, 4f64);
+        assert_eq!(
+            p.to_mathml(POLAR),
+            r#"<math xmlns="http://www.w3.org/1998/Math/MathML"><mn>5.0</mn><mo>∠</mo><mn>53.1</mn><mo>°</mo></math>"#
+        );
+    }
+
+    #[test]
+    fn mathml_renders_rectangular_notation() {
+        let p = Phasor::rect(3f64, -4f64);
+        assert_eq!(
+            p.to_mathml(RECT),
+            r#"<math xmlns="http://www.w3.org/1998/Math/MathML"><mn>3</mn><mo>-</mo><mn>4</mn><mi>j</mi></math>"#
+        );
+    }
+
+    #[test]
+    fn mathml_renders_exponential_notation() {
+        let p = Phasor::rect(3f64, -4f64);
+        assert_eq!(
+            p.to_mathml(EXP),
+            r#"<math xmlns="http://www.w3.org/1998/Math/MathML"><mn>5.00</mn><msup><mi>e</mi><mrow><mo>-</mo><mi>j</mi><mn>0.93</mn></mrow></msup></math>"#
+        );
+    }
+
+    #[test]
+    fn mathml_renders_infinity() {
+        let p = Phasor::rect(f64::NEG_INFINITY, 0f64);
+        assert_eq!(
+            p.to_mathml(RECT),
+            r#"<math xmlns="http://www.w3.org/1998/Math/MathML"><mo>-</mo><mi>∞</mi><mo>+</mo><mn>0</mn><mi>j</mi></math>"#
+        );
+    }
+
+    #[proptest]
+    fn mathml_renders_nan_as_such(#[strategy(any())] mag: f64, #[strategy(nan())] tan: f64) {
+        let p = Phasor { mag, tan };
+        assert_eq!(
+            p.to_mathml(POLAR),
+            r#"<math xmlns="http://www.w3.org/1998/Math/MathML"><mi>NaN</mi></math>"#
+        );
+    }
+
+    #[proptest]
+    fn mathml_is_wrapped_in_a_math_element(p: Phasor) {
+        for format in [POLAR, RECT, EXP] {
+            let s = p.to_mathml(format);
+            assert!(s.starts_with("<math"));
+            assert!(s.ends_with("</math>"));
+        }
+    }
+}
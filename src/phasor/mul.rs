@@ -2,7 +2,7 @@ use super::Phasor;
 use crate::trig::tanaddatan;
 use std::ops::Mul;
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 use wasm_bindgen::prelude::*;
 
 impl Mul for Phasor {
@@ -18,7 +18,7 @@ impl Mul for Phasor {
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 #[wasm_bindgen]
 impl Phasor {
     pub fn mul(&self, rhs: &Phasor) -> Phasor {
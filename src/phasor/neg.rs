@@ -1,7 +1,7 @@
 use super::Phasor;
 use std::ops::Neg;
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 use wasm_bindgen::prelude::*;
 
 impl Neg for Phasor {
@@ -15,7 +15,7 @@ impl Neg for Phasor {
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 #[wasm_bindgen]
 impl Phasor {
     pub fn neg(&self) -> Phasor {
@@ -1,14 +1,13 @@
 use super::Phasor;
-use std::f64::NAN;
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 use wasm_bindgen::prelude::*;
 
-#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), wasm_bindgen)]
 impl Phasor {
     pub fn norm(&self) -> f64 {
         if self.is_nan() {
-            NAN
+            f64::NAN
         } else {
             self.mag.abs()
         }
@@ -0,0 +1,197 @@
+use super::Phasor;
+use std::ops::{Add, Deref, Div, Mul, Neg, Sub};
+
+/// A [`Phasor`] that is guaranteed not to be _NaN_.
+///
+/// Operations that are closed over non-_NaN_ phasors return another [`NotNan`], whereas those
+/// that may yield _NaN_, such as `∞ - ∞`, `0 × ∞`, `0 / 0` and `∞ / ∞`, return an [`Option`].
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct NotNan<T>(T);
+
+impl NotNan<Phasor> {
+    pub fn new(p: Phasor) -> Option<Self> {
+        if p.is_nan() {
+            None
+        } else {
+            Some(NotNan(p))
+        }
+    }
+
+    pub fn into_inner(self) -> Phasor {
+        self.0
+    }
+
+    pub fn conj(self) -> Self {
+        NotNan(self.0.conj())
+    }
+
+    pub fn recip(self) -> Self {
+        NotNan(self.0.recip())
+    }
+
+    pub fn ln(self) -> Self {
+        NotNan(self.0.ln())
+    }
+
+    pub fn exp(self) -> Option<Self> {
+        Self::new(self.0.exp())
+    }
+
+    pub fn log(self, base: f64) -> Option<Self> {
+        Self::new(self.0.log(base))
+    }
+
+    pub fn sinh(self) -> Option<Self> {
+        Self::new(self.0.sinh())
+    }
+
+    pub fn cosh(self) -> Option<Self> {
+        Self::new(self.0.cosh())
+    }
+}
+
+impl Eq for NotNan<Phasor> {}
+
+impl Deref for NotNan<Phasor> {
+    type Target = Phasor;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<NotNan<Phasor>> for Phasor {
+    fn from(p: NotNan<Phasor>) -> Self {
+        p.0
+    }
+}
+
+impl Neg for NotNan<Phasor> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        NotNan(-self.0)
+    }
+}
+
+impl Add for NotNan<Phasor> {
+    type Output = Option<Self>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.0 + rhs.0)
+    }
+}
+
+impl Sub for NotNan<Phasor> {
+    type Output = Option<Self>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.0 - rhs.0)
+    }
+}
+
+impl Mul for NotNan<Phasor> {
+    type Output = Option<Self>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.0 * rhs.0)
+    }
+}
+
+impl Div for NotNan<Phasor> {
+    type Output = Option<Self>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new(self.0 / rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arbitrary::{any, *};
+    use approx::assert_ulps_eq;
+    use proptest::prop_assume;
+    use test_strategy::proptest;
+
+    #[proptest]
+    fn rejects_nan(#[strategy(any())] mag: f64, #[strategy(nan())] tan: f64) {
+        assert_eq!(NotNan::new(Phasor { mag, tan }), None);
+        assert_eq!(NotNan::new(Phasor { mag: tan, tan: mag }), None);
+    }
+
+    #[proptest]
+    fn accepts_anything_but_nan(#[strategy(not_nan())] mag: f64, #[strategy(not_nan())] tan: f64) {
+        let p = Phasor { mag, tan };
+        assert_eq!(NotNan::new(p).map(Phasor::from), Some(p));
+    }
+
+    #[proptest]
+    fn is_reflexive(#[strategy(not_nan())] mag: f64, #[strategy(not_nan())] tan: f64) {
+        let p = NotNan::new(Phasor { mag, tan }).unwrap();
+        assert_eq!(p, p);
+    }
+
+    #[proptest]
+    fn total_operations_are_equivalent_to_those_of_phasor(
+        #[strategy(not_nan())] mag: f64,
+        #[strategy(not_nan())] tan: f64,
+    ) {
+        let p = Phasor { mag, tan };
+        let q = NotNan::new(p).unwrap();
+
+        assert_eq!(*(-q), -p);
+        assert_eq!(*q.conj(), p.conj());
+        assert_eq!(*q.recip(), p.recip());
+        assert_ulps_eq!(*q.ln(), p.ln());
+    }
+
+    #[proptest]
+    fn addition_is_none_only_if_phasors_are_opposite_infinities(
+        #[strategy(not_nan())] a: f64,
+        #[strategy(not_nan())] b: f64,
+        #[strategy(not_nan())] c: f64,
+        #[strategy(not_nan())] d: f64,
+    ) {
+        let p = NotNan::new(Phasor { mag: a, tan: b }).unwrap();
+        let q = NotNan::new(Phasor { mag: c, tan: d }).unwrap();
+
+        if p.is_infinite() && q.is_infinite() {
+            prop_assume!((*p + *q).is_nan());
+            assert_eq!(p + q, None);
+        } else {
+            assert_eq!(p + q, NotNan::new(*p + *q));
+            assert!((p + q).is_some());
+        }
+    }
+
+    #[proptest]
+    fn multiplication_is_none_only_if_phasors_are_zero_and_infinite(
+        #[strategy(not_nan())] a: f64,
+        #[strategy(not_nan())] b: f64,
+        #[strategy(not_nan())] c: f64,
+        #[strategy(not_nan())] d: f64,
+    ) {
+        let p = NotNan::new(Phasor { mag: a, tan: b }).unwrap();
+        let q = NotNan::new(Phasor { mag: c, tan: d }).unwrap();
+
+        let indeterminate = (p.is_zero() && q.is_infinite()) || (p.is_infinite() && q.is_zero());
+
+        assert_eq!((p * q).is_none(), indeterminate);
+    }
+
+    #[proptest]
+    fn division_is_none_only_if_phasors_are_both_zero_or_infinite(
+        #[strategy(not_nan())] a: f64,
+        #[strategy(not_nan())] b: f64,
+        #[strategy(not_nan())] c: f64,
+        #[strategy(not_nan())] d: f64,
+    ) {
+        let p = NotNan::new(Phasor { mag: a, tan: b }).unwrap();
+        let q = NotNan::new(Phasor { mag: c, tan: d }).unwrap();
+
+        let indeterminate = (p.is_zero() && q.is_zero()) || (p.is_infinite() && q.is_infinite());
+
+        assert_eq!((p / q).is_none(), indeterminate);
+    }
+}
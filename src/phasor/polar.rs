@@ -1,28 +1,74 @@
 use super::Phasor;
+use crate::Angle;
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 use wasm_bindgen::prelude::*;
 
 impl Phasor {
-    pub fn polar(mag: f64, angle: f64) -> Self {
+    /// Constructs a phasor given its magnitude and angle, either in radians or as an [`Angle`].
+    ///
+    /// Whole quarter turns of an [`Angle`] are applied exactly, so that, for instance,
+    /// `Phasor::polar(1f64, Angle::degrees(90f64))` is purely imaginary.
+    pub fn polar(mag: f64, angle: impl Into<Angle>) -> Self {
+        let angle = angle.into();
+        let r = angle.remainder();
+
+        let p = Phasor {
+            mag: mag * r.cos().signum(),
+            tan: r.tan(),
+        };
+
+        match angle.quarters() {
+            0 => p,
+            1 => p.quarter_turn(),
+            2 => p.half_turn(),
+            _ => (-p).quarter_turn(),
+        }
+    }
+
+    /// Rotates by `π`, landing on the side of the branch cut that [`Angle::to_radians`] does.
+    fn half_turn(self) -> Self {
         Phasor {
-            mag: mag * angle.cos().signum(),
-            tan: angle.tan(),
+            mag: -self.mag,
+            tan: if self.tan == 0f64 { -0f64 } else { self.tan },
+        }
+    }
+
+    fn quarter_turn(self) -> Self {
+        if self.tan == 0f64 {
+            Phasor {
+                mag: self.mag,
+                tan: f64::INFINITY,
+            }
+        } else {
+            Phasor {
+                mag: -self.mag * self.tan.signum(),
+                tan: -self.tan.recip(),
+            }
         }
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 #[wasm_bindgen]
 pub fn polar(mag: f64, angle: Option<f64>) -> Phasor {
     Phasor::polar(mag, angle.unwrap_or(0f64))
 }
 
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[wasm_bindgen]
+impl Phasor {
+    #[wasm_bindgen(js_name = "polar")]
+    pub fn from_polar(mag: f64, angle: Option<f64>) -> Phasor {
+        polar(mag, angle)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::arbitrary::{any, *};
-    use approx::assert_ulps_eq;
+    use approx::{assert_relative_eq, assert_ulps_eq};
     use test_strategy::proptest;
 
     #[proptest]
@@ -43,6 +89,41 @@ mod tests {
         assert_ulps_eq!(p.angle(), ang.sin().atan2(ang.cos()));
     }
 
+    #[proptest]
+    fn preserves_angle_in_degrees(
+        #[strategy(regular())] mag: f64,
+        #[strategy(-1E3f64..1E3)] deg: f64,
+    ) {
+        let p = Phasor::polar(mag.abs(), Angle::degrees(deg));
+        let q = Phasor::polar(mag.abs(), deg.to_radians());
+        assert_relative_eq!(p, q, epsilon = 1E-12);
+    }
+
+    #[proptest]
+    fn right_angles_are_exact(#[strategy(not_nan())] mag: f64, #[strategy(-100i32..100)] k: i32) {
+        let p = Phasor::polar(mag, Angle::degrees(90f64 * k as f64));
+        assert_eq!(p, Phasor::quarter_turns(mag, k));
+    }
+
+    #[proptest]
+    fn straight_angle_round_trips(#[strategy(regular())] mag: f64) {
+        for deg in [180f64, -180f64] {
+            let a = Angle::degrees(deg);
+            let p = Phasor::polar(mag.abs(), a);
+            assert_eq!(p.angle(), a.to_radians());
+            assert_eq!(p.arg(), a);
+        }
+    }
+
+    #[test]
+    fn right_angle_is_imaginary() {
+        let p = Phasor::polar(1f64, Angle::degrees(90f64));
+        assert_eq!(p, Phasor::I);
+        assert_eq!(p.tan, f64::INFINITY);
+        assert_eq!(p.real(), 0f64);
+        assert_eq!(p.imag(), 1f64);
+    }
+
     #[proptest]
     fn is_real_if_angle_is_zero(#[strategy(not_nan())] mag: f64, #[strategy(zero())] ang: f64) {
         let p = Phasor::polar(mag, ang);
@@ -1,7 +1,7 @@
 use super::Phasor;
 use std::num::FpCategory;
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 use wasm_bindgen::prelude::*;
 
 impl Phasor {
@@ -18,12 +18,21 @@ impl Phasor {
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 #[wasm_bindgen]
 pub fn rect(re: f64, im: Option<f64>) -> Phasor {
     Phasor::rect(re, im.unwrap_or(0f64))
 }
 
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[wasm_bindgen]
+impl Phasor {
+    #[wasm_bindgen(js_name = "rect")]
+    pub fn from_rect(re: f64, im: Option<f64>) -> Phasor {
+        rect(re, im)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
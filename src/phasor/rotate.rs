@@ -0,0 +1,172 @@
+use super::Phasor;
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen::prelude::*;
+
+impl Phasor {
+    /// Rotates by the angle of `by`, disregarding its norm.
+    pub fn rotate(&self, by: Phasor) -> Phasor {
+        *self * by.unit()
+    }
+}
+
+#[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), wasm_bindgen)]
+impl Phasor {
+    /// The phasor of unit norm in the same direction.
+    ///
+    /// Zero phasors keep their nominal direction, so `p.unit().with_norm(p.norm())` is `p`.
+    pub fn unit(&self) -> Phasor {
+        self.with_norm(1f64)
+    }
+
+    /// The phasor of norm `r` in the same direction.
+    #[cfg_attr(
+        all(target_arch = "wasm32", target_os = "unknown"),
+        wasm_bindgen(js_name = "withNorm")
+    )]
+    pub fn with_norm(&self, r: f64) -> Phasor {
+        Phasor {
+            mag: r * self.mag.signum(),
+            tan: self.tan,
+        }
+    }
+
+    /// The phasor of the same norm in the direction of `q`.
+    #[cfg_attr(
+        all(target_arch = "wasm32", target_os = "unknown"),
+        wasm_bindgen(js_name = "withAngleOf")
+    )]
+    pub fn with_angle_of(&self, q: &Phasor) -> Phasor {
+        q.with_norm(self.norm())
+    }
+
+    /// The phasor of unit norm that rotates `self` into the direction of `q`.
+    #[cfg_attr(
+        all(target_arch = "wasm32", target_os = "unknown"),
+        wasm_bindgen(js_name = "angleBetween")
+    )]
+    pub fn angle_between(&self, q: &Phasor) -> Phasor {
+        q.unit() / self.unit()
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[wasm_bindgen]
+impl Phasor {
+    #[wasm_bindgen(js_name = "rotate")]
+    pub fn rotate_by(&self, by: &Phasor) -> Phasor {
+        self.rotate(*by)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arbitrary::{any, *};
+    use approx::{assert_relative_eq, assert_ulps_eq};
+    use test_strategy::proptest;
+
+    #[proptest]
+    fn unit_has_unit_norm(#[strategy(not_nan())] mag: f64, #[strategy(not_nan())] tan: f64) {
+        let p = Phasor { mag, tan };
+        assert_eq!(p.unit().norm(), 1f64);
+        assert_eq!(p.unit().tan, tan);
+    }
+
+    #[proptest]
+    fn unit_preserves_direction(#[strategy(regular())] mag: f64, #[strategy(not_nan())] tan: f64) {
+        let p = Phasor { mag, tan };
+        assert_ulps_eq!(p.unit().angle(), p.angle());
+    }
+
+    #[proptest]
+    fn with_norm_is_inverse_of_unit(
+        #[strategy(not_nan())] mag: f64,
+        #[strategy(not_nan())] tan: f64,
+    ) {
+        let p = Phasor { mag, tan };
+        assert_eq!(p.unit().with_norm(p.norm()), p);
+    }
+
+    #[proptest]
+    fn with_norm_is_nan_if_phasor_is_nan(#[strategy(nan())] mag: f64, #[strategy(any())] r: f64) {
+        let p = Phasor { mag, tan: 0f64 };
+        assert!(p.with_norm(r).is_nan());
+    }
+
+    #[proptest]
+    fn with_angle_of_preserves_norm_and_takes_direction(
+        #[strategy(not_nan())] a: f64,
+        #[strategy(not_nan())] b: f64,
+        #[strategy(regular())] c: f64,
+        #[strategy(not_nan())] d: f64,
+    ) {
+        let p = Phasor { mag: a, tan: b };
+        let q = Phasor { mag: c, tan: d };
+        let r = p.with_angle_of(&q);
+
+        assert_eq!(r.norm(), p.norm());
+        assert_eq!(r.unit(), q.unit());
+    }
+
+    #[proptest]
+    fn rotate_by_unit_is_multiplication(
+        #[strategy(finite())] a: f64,
+        #[strategy(not_nan())] b: f64,
+        #[strategy(not_nan())] d: f64,
+    ) {
+        let p = Phasor { mag: a, tan: b };
+        let q = Phasor { mag: 1f64, tan: d };
+
+        assert_eq!(p.rotate(q), p * q);
+    }
+
+    #[proptest]
+    fn rotate_preserves_norm(
+        #[strategy(not_nan())] a: f64,
+        #[strategy(not_nan())] b: f64,
+        #[strategy(regular())] c: f64,
+        #[strategy(not_nan())] d: f64,
+    ) {
+        let p = Phasor { mag: a, tan: b };
+        let q = Phasor { mag: c, tan: d };
+
+        assert_eq!(p.rotate(q).norm(), p.norm());
+    }
+
+    #[proptest]
+    fn angle_between_rotates_into_direction(
+        #[strategy(regular())] a: f64,
+        #[strategy(not_nan())] b: f64,
+        #[strategy(regular())] c: f64,
+        #[strategy(not_nan())] d: f64,
+    ) {
+        let p = Phasor { mag: a, tan: b };
+        let q = Phasor { mag: c, tan: d };
+        let r = p.angle_between(&q);
+
+        assert_eq!(r.norm(), 1f64);
+        assert_relative_eq!(p.rotate(r).unit(), q.unit(), epsilon = 1E-15);
+    }
+
+    #[proptest]
+    fn angle_between_equals_difference_of_angles(
+        #[strategy(-1.5f64..1.5)] a: f64,
+        #[strategy(-1.5f64..1.5)] b: f64,
+    ) {
+        let r = Phasor::cis(a).angle_between(&Phasor::cis(b));
+        assert_ulps_eq!(r.angle(), b - a, epsilon = 1E-15);
+    }
+
+    #[test]
+    fn chained_rotations_do_not_drift() {
+        let step = Phasor::rect(1f64, 1f64);
+        let mut p = Phasor::ONE;
+
+        for _ in 0..8_000 {
+            p = p.rotate(step);
+        }
+
+        assert_eq!(p, Phasor::ONE);
+    }
+}
@@ -0,0 +1,249 @@
+use super::{Phasor, UlpsDistance};
+use ::approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use std::fmt::{self, Display, Formatter};
+
+/// A borrowed sequence of [`Phasor`]s compared elementwise.
+///
+/// This wrapper only exists to report the first element that differs, for the benefit of
+/// [`assert_phasors_eq!`](crate::assert_phasors_eq); [`approx`](::approx) already compares slices
+/// of phasors elementwise. Arrays, slices and vectors convert into [`Phasors`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Phasors<'a>(pub &'a [Phasor]);
+
+/// The first pair of elements that differ between two sequences of phasors.
+///
+/// Either side is [`None`] if the sequences differ in length and the other one is longer.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Mismatch {
+    pub index: usize,
+    pub left: Option<Phasor>,
+    pub right: Option<Phasor>,
+}
+
+impl Mismatch {
+    pub fn ulps_distance(&self) -> Option<UlpsDistance> {
+        Some(self.left?.ulps_distance(&self.right?))
+    }
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "mismatch at index {}: ", self.index)?;
+
+        match (self.left, self.right) {
+            (Some(p), Some(q)) => write!(f, "{p:?} != {q:?} ({})", p.ulps_distance(&q)),
+            (Some(p), None) => write!(f, "{p:?} has no counterpart on the right"),
+            (None, q) => {
+                let q = q.expect("a mismatch has an element on at least one side");
+                write!(f, "{q:?} has no counterpart on the left")
+            }
+        }
+    }
+}
+
+impl<'a> Phasors<'a> {
+    fn mismatch(&self, other: &Phasors, eq: impl Fn(&Phasor, &Phasor) -> bool) -> Option<Mismatch> {
+        let (l, r) = (self.0, other.0);
+
+        (0..l.len().max(r.len()))
+            .map(|index| Mismatch {
+                index,
+                left: l.get(index).copied(),
+                right: r.get(index).copied(),
+            })
+            .find(|m| match (m.left, m.right) {
+                (Some(p), Some(q)) => !eq(&p, &q),
+                _ => true,
+            })
+    }
+
+    pub fn abs_diff_mismatch(&self, other: &Phasors, e: f64) -> Option<Mismatch> {
+        self.mismatch(other, |p, q| AbsDiffEq::abs_diff_eq(p, q, e))
+    }
+
+    pub fn relative_mismatch(&self, other: &Phasors, e: f64, max: f64) -> Option<Mismatch> {
+        self.mismatch(other, |p, q| RelativeEq::relative_eq(p, q, e, max))
+    }
+
+    pub fn ulps_mismatch(&self, other: &Phasors, e: f64, max: u32) -> Option<Mismatch> {
+        self.mismatch(other, |p, q| UlpsEq::ulps_eq(p, q, e, max))
+    }
+
+    #[doc(hidden)]
+    #[track_caller]
+    pub fn assert_ulps_eq(&self, other: &Phasors, e: Option<f64>, max: Option<u32>) {
+        let e = e.unwrap_or_else(Phasor::default_epsilon);
+        let max = max.unwrap_or_else(Phasor::default_max_ulps);
+
+        if let Some(m) = self.ulps_mismatch(other, e, max) {
+            panic!("assertion `left ≈ right` failed: {m}");
+        }
+    }
+}
+
+/// Asserts that two sequences of phasors are [`UlpsEq`], optionally given `epsilon` and
+/// `max_ulps`, and otherwise panics naming the first [`Mismatch`].
+///
+/// ```should_panic
+/// # use phasor::{assert_phasors_eq, Phasor};
+/// let ps = [Phasor::rect(1f64, 0f64), Phasor::rect(0f64, 1f64)];
+/// let qs = [Phasor::rect(1f64, 0f64), Phasor::rect(0f64, -1f64)];
+///
+/// // Panics with "mismatch at index 1: ...".
+/// assert_phasors_eq!(&ps, &qs, max_ulps = 4);
+/// ```
+#[macro_export]
+macro_rules! assert_phasors_eq {
+    ($left:expr, $right:expr $(, epsilon = $e:expr)? $(, max_ulps = $max:expr)? $(,)?) => {
+        $crate::Phasors::from($left).assert_ulps_eq(
+            &$crate::Phasors::from($right),
+            None $(.or(Some($e)))?,
+            None $(.or(Some($max)))?,
+        )
+    };
+}
+
+impl<'a> From<&'a [Phasor]> for Phasors<'a> {
+    fn from(ps: &'a [Phasor]) -> Self {
+        Phasors(ps)
+    }
+}
+
+impl<'a, const N: usize> From<&'a [Phasor; N]> for Phasors<'a> {
+    fn from(ps: &'a [Phasor; N]) -> Self {
+        Phasors(ps)
+    }
+}
+
+impl<'a> From<&'a Vec<Phasor>> for Phasors<'a> {
+    fn from(ps: &'a Vec<Phasor>) -> Self {
+        Phasors(ps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::{finite, not_nan};
+    use approx::*;
+    use proptest::collection::vec;
+    use std::f64::consts::FRAC_PI_2;
+    use test_strategy::proptest;
+
+    #[proptest]
+    fn same_sequences_are_equal(#[strategy(vec(finite(), 0..10))] ps: Vec<Phasor>) {
+        let l = Phasors::from(&ps);
+
+        assert_eq!(l.abs_diff_mismatch(&l, 0f64), None);
+        assert_eq!(l.relative_mismatch(&l, 0f64, 0f64), None);
+        assert_eq!(l.ulps_mismatch(&l, 0f64, 0), None);
+        assert_phasors_eq!(&ps, &ps);
+    }
+
+    #[proptest]
+    fn reports_first_mismatching_element(
+        #[strategy(vec(finite(), 1..10))] ps: Vec<Phasor>,
+        #[strategy(0..#ps.len())] index: usize,
+    ) {
+        let mut qs = ps.clone();
+        qs[index] = -qs[index];
+        qs.push(Phasor::default());
+
+        let (l, r) = (Phasors::from(&ps), Phasors::from(&qs));
+
+        let mismatch = Mismatch {
+            index,
+            left: Some(ps[index]),
+            right: Some(qs[index]),
+        };
+
+        assert_eq!(l.abs_diff_mismatch(&r, 0f64), Some(mismatch));
+        assert_eq!(l.relative_mismatch(&r, 0f64, 0f64), Some(mismatch));
+        assert_eq!(l.ulps_mismatch(&r, 0f64, 0), Some(mismatch));
+        assert!(mismatch.ulps_distance().unwrap().opposite);
+    }
+
+    #[proptest]
+    fn reports_missing_elements(#[strategy(vec(not_nan(), 0..10))] ps: Vec<Phasor>) {
+        let qs = &ps[..ps.len().saturating_sub(1)];
+        let (l, r) = (Phasors::from(&ps), Phasors::from(qs));
+
+        if let Some(&p) = ps.last() {
+            let mismatch = Mismatch {
+                index: qs.len(),
+                left: Some(p),
+                right: None,
+            };
+
+            assert_eq!(l.ulps_mismatch(&r, 0f64, 0), Some(mismatch));
+            assert_eq!(mismatch.ulps_distance(), None);
+        } else {
+            assert_eq!(l.ulps_mismatch(&r, 0f64, 0), None);
+        }
+    }
+
+    #[test]
+    fn slices_are_comparable_with_approx() {
+        let ps = [Phasor::rect(1f64, 0f64), Phasor::rect(0f64, 1f64)];
+        let qs = [Phasor::polar(1f64, 0f64), Phasor::polar(1f64, FRAC_PI_2)];
+
+        assert_abs_diff_eq!(ps[..], qs[..]);
+        assert_relative_eq!(ps[..], qs[..]);
+        assert_ulps_eq!(ps[..], qs[..]);
+        assert_ulps_ne!(ps[..], qs[..1]);
+    }
+
+    #[test]
+    fn assertion_passes_if_sequences_are_equal() {
+        let ps = [Phasor::rect(1f64, 0f64), Phasor::rect(0f64, 1f64)];
+        let qs = [Phasor::polar(1f64, 0f64), Phasor::polar(1f64, FRAC_PI_2)];
+
+        assert_phasors_eq!(&ps, &qs);
+        assert_phasors_eq!(&ps, &qs, epsilon = f64::EPSILON, max_ulps = 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3")]
+    fn assertion_names_first_mismatching_index() {
+        let ps = vec![Phasor::rect(1f64, 2f64); 5];
+        let mut qs = ps.clone();
+        qs[3] = -qs[3];
+        qs[4] = -qs[4];
+
+        assert_phasors_eq!(&ps, &qs);
+    }
+
+    #[test]
+    fn mismatch_is_displayed_with_distance() {
+        let ps = [Phasor {
+            mag: 1f64,
+            tan: 0f64,
+        }];
+        let qs = [Phasor {
+            mag: -1f64,
+            tan: 0f64,
+        }];
+
+        let mismatch = Phasors::from(&ps)
+            .ulps_mismatch(&Phasors::from(&qs), 0f64, 0)
+            .unwrap();
+
+        assert_eq!(
+            mismatch.to_string(),
+            concat!(
+                "mismatch at index 0: ",
+                "Phasor { mag: 1.0, tan: 0.0 } != Phasor { mag: -1.0, tan: 0.0 } ",
+                "(magnitude: 0 ulps, angle: 0 ulps, opposite directions)"
+            )
+        );
+
+        let mismatch = Phasors::from(&ps[..0])
+            .ulps_mismatch(&Phasors::from(&qs), 0f64, 0)
+            .unwrap();
+
+        assert_eq!(
+            mismatch.to_string(),
+            "mismatch at index 0: Phasor { mag: -1.0, tan: 0.0 } has no counterpart on the left"
+        );
+    }
+}
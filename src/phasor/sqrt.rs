@@ -0,0 +1,123 @@
+use super::Phasor;
+use crate::trig::{cosatan, sinatan};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen::prelude::*;
+
+#[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), wasm_bindgen)]
+impl Phasor {
+    /// The principal square root, whose angle is half that of `self`.
+    pub fn sqrt(self) -> Self {
+        let sign = self.mag.signum();
+        let (c, s) = (cosatan(self.tan) * sign, sinatan(self.tan) * sign);
+
+        // Half-angle tangent, avoiding cancellation on either side of the imaginary axis.
+        let tan = if c >= 0f64 {
+            s / (1f64 + c)
+        } else {
+            (1f64 - c) / s
+        };
+
+        Phasor {
+            mag: self.norm().sqrt(),
+            tan,
+        }
+    }
+
+    /// The square root on the `k`-th branch, which is the principal value for even `k` and its
+    /// opposite for odd `k`.
+    #[cfg_attr(
+        all(target_arch = "wasm32", target_os = "unknown"),
+        wasm_bindgen(js_name = "sqrtBranch")
+    )]
+    pub fn sqrt_branch(self, k: i32) -> Self {
+        if k % 2 == 0 {
+            self.sqrt()
+        } else {
+            -self.sqrt()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arbitrary::{any, *};
+    use approx::{assert_relative_eq, assert_ulps_eq};
+    use test_strategy::proptest;
+
+    #[proptest]
+    fn has_norm_equal_to_square_root_of_norm(
+        #[strategy(not_nan())] mag: f64,
+        #[strategy(not_nan())] tan: f64,
+    ) {
+        let p = Phasor { mag, tan };
+        assert_eq!(p.sqrt().norm(), p.norm().sqrt());
+    }
+
+    #[proptest]
+    fn has_half_the_angle(#[strategy(regular())] mag: f64, #[strategy(not_nan())] tan: f64) {
+        let p = Phasor { mag, tan };
+        assert_ulps_eq!(p.sqrt().angle(), p.angle() / 2f64, epsilon = 1E-15);
+    }
+
+    #[proptest]
+    fn has_nonnegative_real_part(#[strategy(not_nan())] mag: f64, #[strategy(not_nan())] tan: f64) {
+        let p = Phasor { mag, tan };
+        assert!(p.sqrt().real() >= 0f64);
+    }
+
+    #[proptest]
+    fn squares_to_self(
+        #[strategy(-1E100f64..1E100)] re: f64,
+        #[strategy(-1E100f64..1E100)] im: f64,
+        #[strategy(-100i32..100)] k: i32,
+    ) {
+        let p = Phasor::rect(re, im);
+        let q = p.sqrt_branch(k);
+
+        assert_relative_eq!(q * q, p, epsilon = 1E-15 * p.norm(), max_relative = 1E-14);
+    }
+
+    #[proptest]
+    fn equals_exponential_of_half_the_logarithm(
+        #[strategy(1E-100f64..1E100)] mag: f64,
+        #[strategy(not_nan())] tan: f64,
+    ) {
+        let p = Phasor { mag, tan };
+        let q = (p.ln() / Phasor::rect(2f64, 0f64)).exp();
+
+        assert_relative_eq!(p.sqrt(), q, epsilon = 1E-15, max_relative = 1E-12);
+    }
+
+    #[proptest]
+    fn branches_alternate_sign(
+        #[strategy(not_nan())] mag: f64,
+        #[strategy(not_nan())] tan: f64,
+        #[strategy(-100i32..100)] k: i32,
+    ) {
+        let p = Phasor { mag, tan };
+
+        assert_eq!(p.sqrt_branch(k), p.sqrt_branch(k + 2));
+        assert_eq!(p.sqrt_branch(k + 1), -p.sqrt_branch(k));
+    }
+
+    #[test]
+    fn square_root_of_negative_one_is_imaginary() {
+        assert_eq!(Phasor::rect(-1f64, 0f64).sqrt().norm(), 1f64);
+        assert!(Phasor::rect(-1f64, 0f64).sqrt().is_imaginary());
+        assert_eq!(Phasor::rect(0f64, 2f64).sqrt(), Phasor::rect(1f64, 1f64));
+    }
+
+    #[proptest]
+    fn is_nan_if_magnitude_is_nan(#[strategy(nan())] mag: f64, #[strategy(any())] tan: f64) {
+        let p = Phasor { mag, tan };
+        assert!(p.sqrt().is_nan());
+    }
+
+    #[proptest]
+    fn is_nan_if_tangent_is_nan(#[strategy(any())] mag: f64, #[strategy(nan())] tan: f64) {
+        let p = Phasor { mag, tan };
+        assert!(p.sqrt().is_nan());
+    }
+}
@@ -1,7 +1,7 @@
 use super::Phasor;
 use std::ops::Sub;
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 use wasm_bindgen::prelude::*;
 
 impl Sub for Phasor {
@@ -12,7 +12,7 @@ impl Sub for Phasor {
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 #[wasm_bindgen]
 impl Phasor {
     pub fn sub(&self, rhs: &Phasor) -> Phasor {
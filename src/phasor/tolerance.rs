@@ -0,0 +1,265 @@
+use super::Phasor;
+use crate::trig::{cosatan, sinatan};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen::prelude::*;
+
+/// Independent tolerances on magnitude and angle, e.g. "within 0.1% in magnitude and 0.5° in
+/// phase" is `PolarTolerance { rel_mag: 1E-3, abs_angle: 0.5f64.to_radians() }`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PolarTolerance {
+    /// The maximum difference in norm relative to the largest of the two norms.
+    pub rel_mag: f64,
+    /// The maximum angle in radians between the two phasors.
+    pub abs_angle: f64,
+}
+
+impl Default for PolarTolerance {
+    fn default() -> Self {
+        PolarTolerance {
+            rel_mag: f64::EPSILON,
+            abs_angle: f64::EPSILON,
+        }
+    }
+}
+
+impl Phasor {
+    /// Whether the phasors are within `tolerance` of each other in magnitude and in angle.
+    ///
+    /// The angle of a zero phasor is ignored, and an infinite phasor only matches another one.
+    pub fn polar_eq(&self, other: &Phasor, tolerance: PolarTolerance) -> bool {
+        if self.is_nan() || other.is_nan() {
+            return false;
+        }
+
+        let (r, s) = (self.norm(), other.norm());
+
+        if r.is_infinite() != s.is_infinite() {
+            return false;
+        }
+
+        if r != s && (r - s).abs() > tolerance.rel_mag * r.max(s) {
+            return false;
+        }
+
+        if r == 0f64 || s == 0f64 {
+            return true;
+        }
+
+        let (sp, cp) = (sinatan(self.tan), cosatan(self.tan));
+        let (sq, cq) = (sinatan(other.tan), cosatan(other.tan));
+        let sign = self.mag.signum() * other.mag.signum();
+
+        let cross = (sp * cq - cp * sq).abs();
+        let dot = (sp * sq + cp * cq) * sign;
+
+        cross.atan2(dot) <= tolerance.abs_angle
+    }
+
+    /// The euclidean distance between the phasors in the complex plane.
+    pub fn abs_diff(&self, other: &Phasor) -> f64 {
+        (*self - *other).norm()
+    }
+
+    /// Whether the phasors are within a euclidean distance `e` of each other.
+    pub fn euclidean_eq(&self, other: &Phasor, e: f64) -> bool {
+        self.abs_diff(other) <= e
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[wasm_bindgen]
+impl Phasor {
+    #[wasm_bindgen(js_name = "polarEq")]
+    pub fn polar_eq_with(
+        &self,
+        rhs: &Phasor,
+        rel_mag: Option<f64>,
+        abs_angle: Option<f64>,
+    ) -> bool {
+        let default = PolarTolerance::default();
+
+        self.polar_eq(
+            rhs,
+            PolarTolerance {
+                rel_mag: rel_mag.unwrap_or(default.rel_mag),
+                abs_angle: abs_angle.unwrap_or(default.abs_angle),
+            },
+        )
+    }
+
+    #[wasm_bindgen(js_name = "absDiff")]
+    pub fn abs_diff_with(&self, rhs: &Phasor) -> f64 {
+        self.abs_diff(rhs)
+    }
+
+    #[wasm_bindgen(js_name = "euclideanEq")]
+    pub fn euclidean_eq_with(&self, rhs: &Phasor, e: Option<f64>) -> bool {
+        self.euclidean_eq(rhs, e.unwrap_or(f64::EPSILON))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arbitrary::{any, *};
+    use crate::strategy;
+    use approx::assert_ulps_eq;
+    use proptest::prop_assume;
+    use test_strategy::proptest;
+
+    #[proptest]
+    fn polar_eq_same(#[strategy(not_nan())] mag: f64, #[strategy(not_nan())] tan: f64) {
+        let p = Phasor { mag, tan };
+        assert!(p.polar_eq(&p, PolarTolerance::default()));
+    }
+
+    #[proptest]
+    fn polar_eq_scaled(
+        #[strategy(regular())] mag: f64,
+        #[strategy(not_nan())] tan: f64,
+        #[strategy(1E-6f64..1E-1)] rel_mag: f64,
+    ) {
+        let p = Phasor { mag, tan };
+
+        let tolerance = PolarTolerance {
+            rel_mag,
+            abs_angle: 0f64,
+        };
+
+        let q = Phasor {
+            mag: mag * (1f64 - rel_mag / 2f64),
+            tan,
+        };
+
+        assert!(p.polar_eq(&q, tolerance));
+        assert!(q.polar_eq(&p, tolerance));
+
+        let q = Phasor {
+            mag: mag * (1f64 - rel_mag * 2f64),
+            tan,
+        };
+
+        prop_assume!(q.mag.is_normal());
+        assert!(!p.polar_eq(&q, tolerance));
+        assert!(!q.polar_eq(&p, tolerance));
+    }
+
+    #[proptest]
+    fn polar_eq_rotated(
+        #[strategy(regular())] mag: f64,
+        #[strategy(-3f64..3f64)] ang: f64,
+        #[strategy(1E-6f64..1E-1)] abs_angle: f64,
+    ) {
+        let p = Phasor::polar(mag, ang);
+
+        let tolerance = PolarTolerance {
+            rel_mag: 1E-15,
+            abs_angle,
+        };
+
+        let q = Phasor::polar(mag, ang + abs_angle / 2f64);
+        assert!(p.polar_eq(&q, tolerance));
+        assert!(q.polar_eq(&p, tolerance));
+
+        let q = Phasor::polar(mag, ang + abs_angle * 2f64);
+        assert!(!p.polar_eq(&q, tolerance));
+        assert!(!q.polar_eq(&p, tolerance));
+    }
+
+    #[proptest]
+    fn polar_eq_opposite(#[strategy(nonzero())] mag: f64, #[strategy(not_nan())] tan: f64) {
+        let p = Phasor { mag, tan };
+        let q = Phasor { mag: -mag, tan };
+
+        let tolerance = PolarTolerance {
+            rel_mag: 0f64,
+            abs_angle: 3f64,
+        };
+
+        assert!(!p.polar_eq(&q, tolerance));
+    }
+
+    #[proptest]
+    fn polar_eq_ignores_angle_of_zero(
+        #[strategy(zero())] mag: f64,
+        #[strategy(not_nan())] a: f64,
+        #[strategy(not_nan())] b: f64,
+    ) {
+        let p = Phasor { mag, tan: a };
+        let q = Phasor { mag, tan: b };
+        assert!(p.polar_eq(&q, PolarTolerance::default()));
+    }
+
+    #[proptest]
+    fn polar_eq_nan(#[strategy(nan())] nan: f64, #[strategy(any())] any: f64) {
+        let p = Phasor { mag: nan, tan: any };
+        let q = Phasor { mag: any, tan: nan };
+
+        let tolerance = PolarTolerance {
+            rel_mag: f64::INFINITY,
+            abs_angle: f64::INFINITY,
+        };
+
+        assert!(!p.polar_eq(&p, tolerance));
+        assert!(!q.polar_eq(&q, tolerance));
+    }
+
+    #[proptest]
+    fn polar_eq_never_matches_finite_to_infinite(
+        #[strategy(strategy::finite())] p: Phasor,
+        #[strategy(strategy::infinite())] q: Phasor,
+    ) {
+        let tolerance = PolarTolerance {
+            rel_mag: f64::INFINITY,
+            abs_angle: f64::INFINITY,
+        };
+
+        assert!(!p.polar_eq(&q, tolerance));
+        assert!(!q.polar_eq(&p, tolerance));
+    }
+
+    #[proptest]
+    fn abs_diff_equals_distance_between_real_and_imaginary_parts(
+        #[strategy(-1E100f64..1E100)] a: f64,
+        #[strategy(-1E100f64..1E100)] b: f64,
+        #[strategy(-1E100f64..1E100)] c: f64,
+        #[strategy(-1E100f64..1E100)] d: f64,
+    ) {
+        let p = Phasor::rect(a, b);
+        let q = Phasor::rect(c, d);
+
+        prop_assume!((a - c).abs() > 1E-3 * a.abs().max(c.abs()));
+        prop_assume!((b - d).abs() > 1E-3 * b.abs().max(d.abs()));
+
+        assert_ulps_eq!(
+            p.abs_diff(&q),
+            (a - c).hypot(b - d),
+            epsilon = 0f64,
+            max_ulps = 100_000
+        );
+    }
+
+    #[proptest]
+    fn abs_diff_is_symmetric(#[strategy(finite())] a: f64, #[strategy(not_nan())] b: f64) {
+        let p = Phasor { mag: a, tan: b };
+        let q = Phasor::rect(1f64, 1f64);
+
+        assert_ulps_eq!(p.abs_diff(&q), q.abs_diff(&p));
+    }
+
+    #[proptest]
+    fn euclidean_eq_same(#[strategy(finite())] mag: f64, #[strategy(not_nan())] tan: f64) {
+        let p = Phasor { mag, tan };
+        assert!(p.euclidean_eq(&p, 0f64));
+    }
+
+    #[test]
+    fn euclidean_eq_within_radius() {
+        let p = Phasor::rect(3f64, 4f64);
+        let q = Phasor::rect(3.3, 4.4);
+
+        assert!(p.euclidean_eq(&q, 0.51));
+        assert!(!p.euclidean_eq(&q, 0.49));
+    }
+}
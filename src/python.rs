@@ -0,0 +1,491 @@
+//! Python bindings, built by [maturin](https://www.maturin.rs) into an extension module named
+//! `phasor`.
+//!
+//! Besides [`Phasor`] itself, which interoperates with Python numbers, the module exports
+//! vectorized functions that take and return NumPy `complex128` arrays. Every element is
+//! computed exactly as [`Phasor`] would, edge cases included, rather than as NumPy would.
+
+use crate::batch::{BatchError, Layout};
+use crate::{AngleUnit, Format, Notation, Phasor};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use numpy::ndarray::ArrayD;
+use numpy::{AllowTypeChange, Complex64, IntoPyArray, PyArrayDyn, PyArrayLikeDyn};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::slice;
+
+#[cfg(not(feature = "rayon"))]
+use crate::batch as ops;
+
+#[cfg(feature = "rayon")]
+use crate::par as ops;
+
+impl From<BatchError> for PyErr {
+    fn from(e: BatchError) -> Self {
+        PyValueError::new_err(e.to_string())
+    }
+}
+
+/// Either a phasor or anything Python can convert to `complex`.
+#[derive(FromPyObject)]
+enum Operand {
+    Phasor(Phasor),
+    Complex(Complex64),
+}
+
+impl From<Operand> for Phasor {
+    fn from(o: Operand) -> Self {
+        match o {
+            Operand::Phasor(p) => p,
+            Operand::Complex(c) => Phasor::rect(c.re, c.im),
+        }
+    }
+}
+
+#[pymethods]
+impl Phasor {
+    #[new]
+    #[pyo3(signature = (mag = 0f64, tan = 0f64))]
+    fn py_new(mag: f64, tan: f64) -> Self {
+        Phasor { mag, tan }
+    }
+
+    #[classattr]
+    #[pyo3(name = "ZERO")]
+    fn py_zero() -> Self {
+        Phasor::ZERO
+    }
+
+    #[classattr]
+    #[pyo3(name = "ONE")]
+    fn py_one() -> Self {
+        Phasor::ONE
+    }
+
+    #[classattr]
+    #[pyo3(name = "I")]
+    fn py_i() -> Self {
+        Phasor::I
+    }
+
+    #[classattr]
+    #[pyo3(name = "NEG_ONE")]
+    fn py_neg_one() -> Self {
+        Phasor::NEG_ONE
+    }
+
+    #[classattr]
+    #[pyo3(name = "NEG_I")]
+    fn py_neg_i() -> Self {
+        Phasor::NEG_I
+    }
+
+    #[classattr]
+    #[pyo3(name = "INFINITY")]
+    fn py_infinity() -> Self {
+        Phasor::INFINITY
+    }
+
+    #[classattr]
+    #[pyo3(name = "NAN")]
+    fn py_nan() -> Self {
+        Phasor::NAN
+    }
+
+    #[pyo3(name = "real")]
+    fn py_real(&self) -> f64 {
+        self.real()
+    }
+
+    #[pyo3(name = "imag")]
+    fn py_imag(&self) -> f64 {
+        self.imag()
+    }
+
+    #[pyo3(name = "norm")]
+    fn py_norm(&self) -> f64 {
+        self.norm()
+    }
+
+    #[pyo3(name = "angle")]
+    fn py_angle(&self) -> f64 {
+        self.angle()
+    }
+
+    #[pyo3(name = "to_rect")]
+    fn py_to_rect(&self) -> (f64, f64) {
+        self.to_rect()
+    }
+
+    #[pyo3(name = "to_polar")]
+    fn py_to_polar(&self) -> (f64, f64) {
+        self.to_polar()
+    }
+
+    #[pyo3(name = "conj")]
+    fn py_conj(&self) -> Self {
+        self.conj()
+    }
+
+    #[pyo3(name = "recip")]
+    fn py_recip(&self) -> Self {
+        self.recip()
+    }
+
+    #[pyo3(name = "exp")]
+    fn py_exp(&self) -> Self {
+        self.exp()
+    }
+
+    #[pyo3(name = "ln")]
+    fn py_ln(&self) -> Self {
+        self.ln()
+    }
+
+    #[pyo3(name = "ln_branch")]
+    fn py_ln_branch(&self, k: i32) -> Self {
+        self.ln_branch(k)
+    }
+
+    #[pyo3(name = "log")]
+    fn py_log(&self, base: f64) -> Self {
+        self.log(base)
+    }
+
+    #[pyo3(name = "sqrt")]
+    fn py_sqrt(&self) -> Self {
+        self.sqrt()
+    }
+
+    #[pyo3(name = "sqrt_branch")]
+    fn py_sqrt_branch(&self, k: i32) -> Self {
+        self.sqrt_branch(k)
+    }
+
+    #[pyo3(name = "sinh")]
+    fn py_sinh(&self) -> Self {
+        self.sinh()
+    }
+
+    #[pyo3(name = "cosh")]
+    fn py_cosh(&self) -> Self {
+        self.cosh()
+    }
+
+    #[pyo3(name = "is_nan")]
+    fn py_is_nan(&self) -> bool {
+        self.is_nan()
+    }
+
+    #[pyo3(name = "is_infinite")]
+    fn py_is_infinite(&self) -> bool {
+        self.is_infinite()
+    }
+
+    #[pyo3(name = "is_finite")]
+    fn py_is_finite(&self) -> bool {
+        self.is_finite()
+    }
+
+    #[pyo3(name = "is_zero")]
+    fn py_is_zero(&self) -> bool {
+        self.is_zero()
+    }
+
+    #[pyo3(name = "is_subnormal")]
+    fn py_is_subnormal(&self) -> bool {
+        self.is_subnormal()
+    }
+
+    #[pyo3(name = "is_normal")]
+    fn py_is_normal(&self) -> bool {
+        self.is_normal()
+    }
+
+    #[pyo3(name = "is_real")]
+    fn py_is_real(&self) -> bool {
+        self.is_real()
+    }
+
+    #[pyo3(name = "is_imaginary")]
+    fn py_is_imaginary(&self) -> bool {
+        self.is_imaginary()
+    }
+
+    #[pyo3(name = "abs_diff_eq", signature = (other, epsilon = f64::EPSILON))]
+    fn py_abs_diff_eq(&self, other: Operand, epsilon: f64) -> bool {
+        AbsDiffEq::abs_diff_eq(self, &other.into(), epsilon)
+    }
+
+    #[pyo3(name = "relative_eq", signature = (other, epsilon = f64::EPSILON, max_relative = f64::EPSILON))]
+    fn py_relative_eq(&self, other: Operand, epsilon: f64, max_relative: f64) -> bool {
+        RelativeEq::relative_eq(self, &other.into(), epsilon, max_relative)
+    }
+
+    #[pyo3(name = "ulps_eq", signature = (other, epsilon = f64::EPSILON, max_ulps = 4))]
+    fn py_ulps_eq(&self, other: Operand, epsilon: f64, max_ulps: u32) -> bool {
+        UlpsEq::ulps_eq(self, &other.into(), epsilon, max_ulps)
+    }
+
+    #[pyo3(name = "format", signature = (notation = "polar", unit = "rad", precision = None, engineering = false))]
+    fn py_format(
+        &self,
+        notation: &str,
+        unit: &str,
+        precision: Option<usize>,
+        engineering: bool,
+    ) -> PyResult<String> {
+        let format = Format {
+            notation: match notation {
+                "polar" => Notation::Polar,
+                "rect" => Notation::Rect,
+                "exp" => Notation::Exp,
+                n => return Err(PyValueError::new_err(format!("invalid notation `{n}`"))),
+            },
+
+            unit: match unit {
+                "rad" => AngleUnit::Radians,
+                "deg" => AngleUnit::Degrees,
+                u => return Err(PyValueError::new_err(format!("invalid unit `{u}`"))),
+            },
+
+            precision,
+            engineering,
+        };
+
+        Ok(self.format(format).to_string())
+    }
+
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __complex__(&self) -> Complex64 {
+        let (re, im) = self.to_rect();
+        Complex64::new(re, im)
+    }
+
+    fn __bool__(&self) -> bool {
+        !self.is_zero()
+    }
+
+    fn __eq__(&self, other: Operand) -> bool {
+        *self == other.into()
+    }
+
+    fn __abs__(&self) -> f64 {
+        self.norm()
+    }
+
+    fn __neg__(&self) -> Self {
+        -*self
+    }
+
+    fn __pos__(&self) -> Self {
+        *self
+    }
+
+    fn __add__(&self, rhs: Operand) -> Self {
+        *self + Phasor::from(rhs)
+    }
+
+    fn __radd__(&self, lhs: Operand) -> Self {
+        Phasor::from(lhs) + *self
+    }
+
+    fn __sub__(&self, rhs: Operand) -> Self {
+        *self - Phasor::from(rhs)
+    }
+
+    fn __rsub__(&self, lhs: Operand) -> Self {
+        Phasor::from(lhs) - *self
+    }
+
+    fn __mul__(&self, rhs: Operand) -> Self {
+        *self * Phasor::from(rhs)
+    }
+
+    fn __rmul__(&self, lhs: Operand) -> Self {
+        Phasor::from(lhs) * *self
+    }
+
+    fn __truediv__(&self, rhs: Operand) -> Self {
+        *self / Phasor::from(rhs)
+    }
+
+    fn __rtruediv__(&self, lhs: Operand) -> Self {
+        Phasor::from(lhs) / *self
+    }
+
+    fn __getnewargs__(&self) -> (f64, f64) {
+        (self.mag, self.tan)
+    }
+}
+
+/// Constructs a phasor given its real and imaginary parts.
+#[pyfunction]
+#[pyo3(signature = (re, im = 0f64))]
+fn rect(re: f64, im: f64) -> Phasor {
+    Phasor::rect(re, im)
+}
+
+/// Constructs a phasor given its magnitude and angle in radians.
+#[pyfunction]
+#[pyo3(signature = (mag, ang = 0f64))]
+fn polar(mag: f64, ang: f64) -> Phasor {
+    Phasor::polar(mag, ang)
+}
+
+/// Constructs the phasor of unit norm at the given angle.
+#[pyfunction]
+fn cis(ang: f64) -> Phasor {
+    Phasor::cis(ang)
+}
+
+/// Constructs a purely imaginary phasor.
+#[pyfunction]
+#[pyo3(signature = (im = 1f64))]
+fn i(im: f64) -> Phasor {
+    Phasor::quarter_turns(im, 1)
+}
+
+type Array<'py> = PyArrayLikeDyn<'py, Complex64, AllowTypeChange>;
+type Complex<'py> = Bound<'py, PyArrayDyn<Complex64>>;
+type Real<'py> = Bound<'py, PyArrayDyn<f64>>;
+type BinaryOp = fn(&mut [f64], &[f64], Layout) -> Result<(), BatchError>;
+
+/// Copies `a` into a contiguous array.
+fn owned(a: &Array) -> ArrayD<Complex64> {
+    a.as_array().as_standard_layout().into_owned()
+}
+
+/// Views a contiguous array as pairs of real and imaginary parts.
+fn pairs(a: &mut ArrayD<Complex64>) -> &mut [f64] {
+    let s = a.as_slice_mut().expect("array is contiguous");
+
+    // SAFETY: `Complex64` is laid out as its real part followed by its imaginary part.
+    unsafe { slice::from_raw_parts_mut(s.as_mut_ptr().cast(), 2 * s.len()) }
+}
+
+/// Applies `op` to `lhs` and `rhs`, either of which may hold a single element to be broadcast.
+fn binary<'py>(
+    py: Python<'py>,
+    lhs: Array<'py>,
+    rhs: Array<'py>,
+    op: BinaryOp,
+) -> PyResult<Complex<'py>> {
+    let (mut l, mut r) = (owned(&lhs), owned(&rhs));
+
+    if l.len() == 1 && r.len() != 1 {
+        l = ArrayD::from_elem(r.raw_dim(), l.as_slice().expect("array is contiguous")[0]);
+    } else if r.len() != 1 && l.shape() != r.shape() {
+        let (l, r) = (l.shape(), r.shape());
+        return Err(PyValueError::new_err(format!(
+            "shapes {l:?} and {r:?} mismatch"
+        )));
+    }
+
+    py.detach(|| op(pairs(&mut l), pairs(&mut r), Layout::Rect))?;
+    Ok(l.into_pyarray(py))
+}
+
+/// Applies `op` to every element of `a`.
+fn unary<'py>(
+    py: Python<'py>,
+    a: Array<'py>,
+    op: impl FnOnce(&mut [f64]) -> Result<(), BatchError> + Send,
+) -> PyResult<Complex<'py>> {
+    let mut a = owned(&a);
+    py.detach(|| op(pairs(&mut a)))?;
+    Ok(a.into_pyarray(py))
+}
+
+/// Maps every element of `a` to a real number through `op`.
+fn real<'py>(
+    py: Python<'py>,
+    a: Array<'py>,
+    op: fn(&[f64], Layout) -> Result<Vec<f64>, BatchError>,
+) -> PyResult<Real<'py>> {
+    let mut a = owned(&a);
+    let v = py.detach(|| op(pairs(&mut a), Layout::Rect))?;
+    let r = ArrayD::from_shape_vec(a.raw_dim(), v).expect("one real number per element");
+    Ok(r.into_pyarray(py))
+}
+
+macro_rules! binary {
+    ($($name:ident),*) => {
+        $(
+            #[doc = concat!("Vectorized [`Phasor::", stringify!($name), "`].")]
+            #[pyfunction]
+            fn $name<'py>(py: Python<'py>, lhs: Array<'py>, rhs: Array<'py>) -> PyResult<Complex<'py>> {
+                binary(py, lhs, rhs, ops::$name)
+            }
+        )*
+    };
+}
+
+macro_rules! unary {
+    ($($name:ident),*) => {
+        $(
+            #[doc = concat!("Vectorized [`Phasor::", stringify!($name), "`].")]
+            #[pyfunction]
+            fn $name<'py>(py: Python<'py>, a: Array<'py>) -> PyResult<Complex<'py>> {
+                unary(py, a, |buf| ops::$name(buf, Layout::Rect))
+            }
+        )*
+    };
+}
+
+binary!(add, sub, mul, div);
+unary!(neg, conj, recip, exp, ln, sqrt, sinh, cosh);
+
+/// Vectorized [`Phasor::log`].
+#[pyfunction]
+fn log<'py>(py: Python<'py>, a: Array<'py>, base: f64) -> PyResult<Complex<'py>> {
+    unary(py, a, |buf| ops::log(buf, base, Layout::Rect))
+}
+
+/// Vectorized [`Phasor::norm`].
+#[pyfunction]
+fn norm<'py>(py: Python<'py>, a: Array<'py>) -> PyResult<Real<'py>> {
+    real(py, a, ops::norm)
+}
+
+/// Vectorized [`Phasor::angle`].
+#[pyfunction]
+fn angle<'py>(py: Python<'py>, a: Array<'py>) -> PyResult<Real<'py>> {
+    real(py, a, ops::angle)
+}
+
+#[pymodule]
+#[pyo3(name = "phasor")]
+fn py_phasor(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Phasor>()?;
+
+    m.add_function(wrap_pyfunction!(rect, m)?)?;
+    m.add_function(wrap_pyfunction!(polar, m)?)?;
+    m.add_function(wrap_pyfunction!(cis, m)?)?;
+    m.add_function(wrap_pyfunction!(i, m)?)?;
+
+    m.add_function(wrap_pyfunction!(add, m)?)?;
+    m.add_function(wrap_pyfunction!(sub, m)?)?;
+    m.add_function(wrap_pyfunction!(mul, m)?)?;
+    m.add_function(wrap_pyfunction!(div, m)?)?;
+    m.add_function(wrap_pyfunction!(neg, m)?)?;
+    m.add_function(wrap_pyfunction!(conj, m)?)?;
+    m.add_function(wrap_pyfunction!(recip, m)?)?;
+    m.add_function(wrap_pyfunction!(exp, m)?)?;
+    m.add_function(wrap_pyfunction!(ln, m)?)?;
+    m.add_function(wrap_pyfunction!(log, m)?)?;
+    m.add_function(wrap_pyfunction!(sqrt, m)?)?;
+    m.add_function(wrap_pyfunction!(sinh, m)?)?;
+    m.add_function(wrap_pyfunction!(cosh, m)?)?;
+    m.add_function(wrap_pyfunction!(norm, m)?)?;
+    m.add_function(wrap_pyfunction!(angle, m)?)?;
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+//! Summation in a fixed order, so that sequential and parallel reductions agree bit for bit.
+//!
+//! Terms are first summed sequentially in blocks of [`BLOCK`], whose partial sums are then
+//! combined pairwise. Neither step depends on how the blocks are scheduled, let alone on the
+//! number of threads.
+
+use crate::Phasor;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// The number of terms summed sequentially into each partial sum.
+const BLOCK: usize = 1024;
+
+fn block(n: usize, b: usize, term: &impl Fn(usize) -> Phasor) -> Phasor {
+    (b * BLOCK..n.min((b + 1) * BLOCK)).fold(Phasor::ZERO, |acc, i| acc + term(i))
+}
+
+fn combine(mut partials: Vec<Phasor>) -> Phasor {
+    while partials.len() > 1 {
+        partials = partials
+            .chunks(2)
+            .map(|c| c.iter().skip(1).fold(c[0], |acc, &p| acc + p))
+            .collect();
+    }
+
+    partials.pop().unwrap_or(Phasor::ZERO)
+}
+
+/// Sums `term(i)` for every `i` in `0..n`.
+pub(crate) fn sum(n: usize, term: impl Fn(usize) -> Phasor) -> Phasor {
+    combine((0..n.div_ceil(BLOCK)).map(|b| block(n, b, &term)).collect())
+}
+
+/// Sums `term(i)` for every `i` in `0..n` in parallel, yielding exactly the same as [`sum`].
+#[cfg(feature = "rayon")]
+pub(crate) fn par_sum(n: usize, term: impl Fn(usize) -> Phasor + Sync) -> Phasor {
+    let partials = (0..n.div_ceil(BLOCK))
+        .into_par_iter()
+        .map(|b| block(n, b, &term))
+        .collect();
+
+    combine(partials)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::any;
+    use proptest::collection::vec;
+    use test_strategy::proptest;
+
+    #[test]
+    fn empty_sum_is_zero() {
+        assert_eq!(sum(0, |_| unreachable!()), Phasor::ZERO);
+    }
+
+    #[proptest(cases = 64)]
+    fn sum_of_single_block_is_sequential(#[strategy(vec(any(), 0..BLOCK))] ps: Vec<Phasor>) {
+        let expected = ps.iter().fold(Phasor::ZERO, |acc, &p| acc + p);
+        let s = sum(ps.len(), |i| ps[i]);
+        assert_eq!(s.to_le_bytes(), expected.to_le_bytes());
+    }
+
+    #[proptest]
+    fn partial_sums_are_combined_pairwise(#[strategy(vec(any(), 3))] ps: Vec<Phasor>) {
+        let expected = (ps[0] + ps[1]) + ps[2];
+        let s = combine(ps);
+        assert_eq!(s.to_le_bytes(), expected.to_le_bytes());
+    }
+}
@@ -0,0 +1,656 @@
+//! Elementwise kernels over phasors stored as separate arrays of magnitudes and tangents.
+//!
+//! On x86-64 processors that support AVX2, detected at runtime, four phasors are processed at a
+//! time, while wasm builds with the `simd128` target feature enabled process two at a time. The
+//! vectorized kernels perform exactly the same sequence of IEEE 754 operations as
+//! their scalar counterparts, which also serve as the fallback elsewhere, so results are bit for
+//! bit identical either way. The only exception is `hypot`, which has no vector instruction and
+//! is thus evaluated lane by lane through the very same library call.
+
+use crate::Phasor;
+use std::ops::{Add, Div, Mul};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen::prelude::*;
+
+/// Whether this is the build with the `simd128` target feature enabled.
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[wasm_bindgen(js_name = "isSimd")]
+pub fn is_simd() -> bool {
+    cfg!(target_feature = "simd128")
+}
+
+fn zip_with(
+    (mag, tan): (&mut [f64], &mut [f64]),
+    (rmag, rtan): (&[f64], &[f64]),
+    f: impl Fn(Phasor, Phasor) -> Phasor,
+) {
+    let lhs = mag.iter_mut().zip(tan);
+    for ((mag, tan), (&rm, &rt)) in lhs.zip(rmag.iter().zip(rtan)) {
+        let p = f(
+            Phasor {
+                mag: *mag,
+                tan: *tan,
+            },
+            Phasor { mag: rm, tan: rt },
+        );
+
+        *mag = p.mag;
+        *tan = p.tan;
+    }
+}
+
+/// An elementwise kernel, updating the phasors on the left-hand side in place.
+pub(crate) type Kernel = fn((&mut [f64], &mut [f64]), (&[f64], &[f64]));
+
+macro_rules! kernel {
+    ($(#[$attr:meta])* $name:ident, $scalar:expr) => {
+        $(#[$attr])*
+        pub(crate) fn $name(lhs: (&mut [f64], &mut [f64]), rhs: (&[f64], &[f64])) {
+            assert_eq!(lhs.0.len(), lhs.1.len());
+            assert_eq!(lhs.0.len(), rhs.0.len());
+            assert_eq!(lhs.0.len(), rhs.1.len());
+
+            #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+            {
+                simd128::run(lhs, rhs, simd128::$name, $scalar)
+            }
+
+            #[cfg(not(all(target_arch = "wasm32", target_feature = "simd128")))]
+            {
+                #[cfg(target_arch = "x86_64")]
+                if is_x86_feature_detected!("avx2") {
+                    // SAFETY: AVX2 support has just been detected.
+                    return unsafe { avx2::run(lhs, rhs, avx2::$name, $scalar) };
+                }
+
+                zip_with(lhs, rhs, $scalar)
+            }
+        }
+    };
+}
+
+kernel!(
+    /// Adds `rhs` to `lhs` elementwise.
+    add,
+    Add::add
+);
+
+kernel!(
+    /// Multiplies `lhs` by `rhs` elementwise.
+    mul,
+    Mul::mul
+);
+
+kernel!(
+    /// Divides `lhs` by `rhs` elementwise.
+    div,
+    Div::div
+);
+
+#[cfg(target_arch = "x86_64")]
+mod avx2 {
+    use super::zip_with;
+    use crate::Phasor;
+    use std::arch::x86_64::*;
+    use std::f64::consts::SQRT_2;
+
+    const LANES: usize = 4;
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn splat(x: f64) -> __m256d {
+        _mm256_set1_pd(x)
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn abs(x: __m256d) -> __m256d {
+        _mm256_andnot_pd(splat(-0f64), x)
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn neg(x: __m256d) -> __m256d {
+        _mm256_xor_pd(splat(-0f64), x)
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn copysign(x: __m256d, sign: __m256d) -> __m256d {
+        _mm256_or_pd(abs(x), _mm256_and_pd(splat(-0f64), sign))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn is_nan(x: __m256d) -> __m256d {
+        _mm256_cmp_pd::<_CMP_UNORD_Q>(x, x)
+    }
+
+    /// Selects `b` where the sign bit of `mask` is set and `a` elsewhere.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn select(a: __m256d, b: __m256d, mask: __m256d) -> __m256d {
+        _mm256_blendv_pd(a, b, mask)
+    }
+
+    /// Equivalent to [`f64::signum`], including the _NaN_ it returns.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn signum(x: __m256d) -> __m256d {
+        select(copysign(splat(1f64), x), splat(f64::NAN), is_nan(x))
+    }
+
+    /// Equivalent to `x.min(1f64)` for non-negative `x`, including _NaN_, which yields 1.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn min1(x: __m256d) -> __m256d {
+        _mm256_min_pd(x, splat(1f64))
+    }
+
+    /// Equivalent to `x.max(1f64)` for non-negative `x`, including _NaN_, which yields 1.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn max1(x: __m256d) -> __m256d {
+        _mm256_max_pd(x, splat(1f64))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn recip(x: __m256d) -> __m256d {
+        _mm256_div_pd(splat(1f64), x)
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn lanes(x: __m256d) -> [f64; LANES] {
+        let mut a = [0f64; LANES];
+        _mm256_storeu_pd(a.as_mut_ptr(), x);
+        a
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn hypot(x: __m256d, y: __m256d) -> __m256d {
+        let (x, y) = (lanes(x), lanes(y));
+        _mm256_setr_pd(
+            x[0].hypot(y[0]),
+            x[1].hypot(y[1]),
+            x[2].hypot(y[2]),
+            x[3].hypot(y[3]),
+        )
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn cosatan2(s: __m256d, c: __m256d) -> __m256d {
+        let (abs_s, abs_c) = (abs(s), abs(c));
+        let gt = _mm256_cmp_pd::<_CMP_GT_OQ>(abs_s, abs_c);
+        let lt = _mm256_cmp_pd::<_CMP_LT_OQ>(abs_s, abs_c);
+
+        let (c_s, s_c) = (_mm256_div_pd(c, s), _mm256_div_pd(s, c));
+        let h = hypot(splat(1f64), select(s_c, c_s, gt));
+
+        let r = copysign(splat(SQRT_2.recip()), c);
+        let r = select(r, c, is_nan(c));
+        let r = select(r, copysign(s, c), is_nan(s));
+        let r = select(r, copysign(recip(h), c), lt);
+        select(r, _mm256_div_pd(copysign(c_s, c), h), gt)
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn sinatan2(s: __m256d, c: __m256d) -> __m256d {
+        copysign(cosatan2(c, s), s)
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn sinatan(x: __m256d) -> __m256d {
+        sinatan2(x, splat(1f64))
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn cosatan(x: __m256d) -> __m256d {
+        cosatan2(x, splat(1f64))
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn tansubatan(x: __m256d, y: __m256d) -> (__m256d, __m256d) {
+        let xs = _mm256_mul_pd(min1(abs(x)), signum(x));
+        let xc = recip(max1(abs(x)));
+
+        let ys = _mm256_mul_pd(min1(abs(y)), signum(y));
+        let yc = recip(max1(abs(y)));
+
+        (
+            _mm256_sub_pd(_mm256_mul_pd(xs, yc), _mm256_mul_pd(xc, ys)),
+            _mm256_add_pd(_mm256_mul_pd(xs, ys), _mm256_mul_pd(xc, yc)),
+        )
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn tanaddatan(x: __m256d, y: __m256d) -> (__m256d, __m256d) {
+        tansubatan(x, neg(y))
+    }
+
+    type Kernel = unsafe fn(__m256d, __m256d, __m256d, __m256d) -> (__m256d, __m256d);
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn add(
+        am: __m256d,
+        at: __m256d,
+        bm: __m256d,
+        bt: __m256d,
+    ) -> (__m256d, __m256d) {
+        let u = sinatan2(am, bm);
+        let v = cosatan2(am, bm);
+
+        let (ure, uim) = (_mm256_mul_pd(u, cosatan(at)), _mm256_mul_pd(u, sinatan(at)));
+        let (vre, vim) = (_mm256_mul_pd(v, cosatan(bt)), _mm256_mul_pd(v, sinatan(bt)));
+        let (re, im) = (_mm256_add_pd(ure, vre), _mm256_add_pd(uim, vim));
+
+        let gt = _mm256_cmp_pd::<_CMP_GT_OQ>(abs(u), abs(v));
+        let w = select(v, u, gt);
+        let m = select(bm, am, gt);
+        let mag = _mm256_mul_pd(
+            hypot(_mm256_div_pd(re, w), _mm256_div_pd(im, w)),
+            copysign(m, re),
+        );
+
+        let nonzero = _mm256_or_pd(
+            _mm256_cmp_pd::<_CMP_NEQ_UQ>(im, splat(0f64)),
+            _mm256_cmp_pd::<_CMP_NEQ_UQ>(re, splat(0f64)),
+        );
+
+        let tan = neg(recip(at));
+        let tan = select(recip(uim), tan, _mm256_xor_pd(uim, vim));
+        let tan = select(ure, tan, _mm256_xor_pd(ure, vre));
+        let tan = select(tan, _mm256_div_pd(im, re), nonzero);
+
+        (mag, tan)
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn mul(
+        am: __m256d,
+        at: __m256d,
+        bm: __m256d,
+        bt: __m256d,
+    ) -> (__m256d, __m256d) {
+        let (s, c) = tanaddatan(at, bt);
+        let mag = _mm256_mul_pd(_mm256_mul_pd(am, bm), signum(c));
+        (mag, _mm256_div_pd(s, c))
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn div(
+        am: __m256d,
+        at: __m256d,
+        bm: __m256d,
+        bt: __m256d,
+    ) -> (__m256d, __m256d) {
+        let (s, c) = tansubatan(at, bt);
+        let mag = _mm256_div_pd(_mm256_div_pd(am, bm), signum(c));
+        (mag, _mm256_div_pd(s, c))
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn run(
+        (mag, tan): (&mut [f64], &mut [f64]),
+        (rmag, rtan): (&[f64], &[f64]),
+        kernel: Kernel,
+        scalar: impl Fn(Phasor, Phasor) -> Phasor,
+    ) {
+        let n = mag.len() - mag.len() % LANES;
+
+        for i in (0..n).step_by(LANES) {
+            let (m, t) = kernel(
+                _mm256_loadu_pd(mag[i..].as_ptr()),
+                _mm256_loadu_pd(tan[i..].as_ptr()),
+                _mm256_loadu_pd(rmag[i..].as_ptr()),
+                _mm256_loadu_pd(rtan[i..].as_ptr()),
+            );
+
+            _mm256_storeu_pd(mag[i..].as_mut_ptr(), m);
+            _mm256_storeu_pd(tan[i..].as_mut_ptr(), t);
+        }
+
+        zip_with(
+            (&mut mag[n..], &mut tan[n..]),
+            (&rmag[n..], &rtan[n..]),
+            scalar,
+        )
+    }
+
+    #[cfg(test)]
+    pub(super) mod tests {
+        use super::*;
+
+        pub fn unary(f: unsafe fn(__m256d) -> __m256d, x: [f64; LANES]) -> [f64; LANES] {
+            unsafe { lanes(f(_mm256_loadu_pd(x.as_ptr()))) }
+        }
+
+        pub fn binary(
+            f: unsafe fn(__m256d, __m256d) -> __m256d,
+            x: [f64; LANES],
+            y: [f64; LANES],
+        ) -> [f64; LANES] {
+            unsafe { lanes(f(_mm256_loadu_pd(x.as_ptr()), _mm256_loadu_pd(y.as_ptr()))) }
+        }
+
+        pub fn pair(
+            f: unsafe fn(__m256d, __m256d) -> (__m256d, __m256d),
+            x: [f64; LANES],
+            y: [f64; LANES],
+        ) -> ([f64; LANES], [f64; LANES]) {
+            unsafe {
+                let (s, c) = f(_mm256_loadu_pd(x.as_ptr()), _mm256_loadu_pd(y.as_ptr()));
+                (lanes(s), lanes(c))
+            }
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+mod simd128 {
+    use super::zip_with;
+    use crate::Phasor;
+    use std::arch::wasm32::*;
+    use std::f64::consts::SQRT_2;
+
+    const LANES: usize = 2;
+
+    fn copysign(x: v128, sign: v128) -> v128 {
+        v128_or(f64x2_abs(x), v128_and(f64x2_splat(-0f64), sign))
+    }
+
+    fn is_nan(x: v128) -> v128 {
+        f64x2_ne(x, x)
+    }
+
+    /// Selects `b` where `mask` is set and `a` elsewhere.
+    fn select(a: v128, b: v128, mask: v128) -> v128 {
+        v128_bitselect(b, a, mask)
+    }
+
+    /// Broadcasts the sign bit of each lane to the entire lane.
+    fn sign_mask(x: v128) -> v128 {
+        i64x2_shr(x, 63)
+    }
+
+    /// Equivalent to [`f64::signum`], including the _NaN_ it returns.
+    fn signum(x: v128) -> v128 {
+        select(
+            copysign(f64x2_splat(1f64), x),
+            f64x2_splat(f64::NAN),
+            is_nan(x),
+        )
+    }
+
+    /// Equivalent to `x.min(1f64)` for non-negative `x`, including _NaN_, which yields 1.
+    fn min1(x: v128) -> v128 {
+        f64x2_pmin(f64x2_splat(1f64), x)
+    }
+
+    /// Equivalent to `x.max(1f64)` for non-negative `x`, including _NaN_, which yields 1.
+    fn max1(x: v128) -> v128 {
+        f64x2_pmax(f64x2_splat(1f64), x)
+    }
+
+    fn recip(x: v128) -> v128 {
+        f64x2_div(f64x2_splat(1f64), x)
+    }
+
+    fn hypot(x: v128, y: v128) -> v128 {
+        f64x2(
+            f64x2_extract_lane::<0>(x).hypot(f64x2_extract_lane::<0>(y)),
+            f64x2_extract_lane::<1>(x).hypot(f64x2_extract_lane::<1>(y)),
+        )
+    }
+
+    fn cosatan2(s: v128, c: v128) -> v128 {
+        let (abs_s, abs_c) = (f64x2_abs(s), f64x2_abs(c));
+        let gt = f64x2_gt(abs_s, abs_c);
+        let lt = f64x2_lt(abs_s, abs_c);
+
+        let (c_s, s_c) = (f64x2_div(c, s), f64x2_div(s, c));
+        let h = hypot(f64x2_splat(1f64), select(s_c, c_s, gt));
+
+        let r = copysign(f64x2_splat(SQRT_2.recip()), c);
+        let r = select(r, c, is_nan(c));
+        let r = select(r, copysign(s, c), is_nan(s));
+        let r = select(r, copysign(recip(h), c), lt);
+        select(r, f64x2_div(copysign(c_s, c), h), gt)
+    }
+
+    fn sinatan2(s: v128, c: v128) -> v128 {
+        copysign(cosatan2(c, s), s)
+    }
+
+    fn sinatan(x: v128) -> v128 {
+        sinatan2(x, f64x2_splat(1f64))
+    }
+
+    fn cosatan(x: v128) -> v128 {
+        cosatan2(x, f64x2_splat(1f64))
+    }
+
+    fn tansubatan(x: v128, y: v128) -> (v128, v128) {
+        let xs = f64x2_mul(min1(f64x2_abs(x)), signum(x));
+        let xc = recip(max1(f64x2_abs(x)));
+
+        let ys = f64x2_mul(min1(f64x2_abs(y)), signum(y));
+        let yc = recip(max1(f64x2_abs(y)));
+
+        (
+            f64x2_sub(f64x2_mul(xs, yc), f64x2_mul(xc, ys)),
+            f64x2_add(f64x2_mul(xs, ys), f64x2_mul(xc, yc)),
+        )
+    }
+
+    fn tanaddatan(x: v128, y: v128) -> (v128, v128) {
+        tansubatan(x, f64x2_neg(y))
+    }
+
+    type Kernel = fn(v128, v128, v128, v128) -> (v128, v128);
+
+    pub(super) fn add(am: v128, at: v128, bm: v128, bt: v128) -> (v128, v128) {
+        let u = sinatan2(am, bm);
+        let v = cosatan2(am, bm);
+
+        let (ure, uim) = (f64x2_mul(u, cosatan(at)), f64x2_mul(u, sinatan(at)));
+        let (vre, vim) = (f64x2_mul(v, cosatan(bt)), f64x2_mul(v, sinatan(bt)));
+        let (re, im) = (f64x2_add(ure, vre), f64x2_add(uim, vim));
+
+        let gt = f64x2_gt(f64x2_abs(u), f64x2_abs(v));
+        let w = select(v, u, gt);
+        let m = select(bm, am, gt);
+        let mag = f64x2_mul(hypot(f64x2_div(re, w), f64x2_div(im, w)), copysign(m, re));
+
+        let zero = f64x2_splat(0f64);
+        let nonzero = v128_or(f64x2_ne(im, zero), f64x2_ne(re, zero));
+
+        let tan = f64x2_neg(recip(at));
+        let tan = select(recip(uim), tan, sign_mask(v128_xor(uim, vim)));
+        let tan = select(ure, tan, sign_mask(v128_xor(ure, vre)));
+        let tan = select(tan, f64x2_div(im, re), nonzero);
+
+        (mag, tan)
+    }
+
+    pub(super) fn mul(am: v128, at: v128, bm: v128, bt: v128) -> (v128, v128) {
+        let (s, c) = tanaddatan(at, bt);
+        let mag = f64x2_mul(f64x2_mul(am, bm), signum(c));
+        (mag, f64x2_div(s, c))
+    }
+
+    pub(super) fn div(am: v128, at: v128, bm: v128, bt: v128) -> (v128, v128) {
+        let (s, c) = tansubatan(at, bt);
+        let mag = f64x2_div(f64x2_div(am, bm), signum(c));
+        (mag, f64x2_div(s, c))
+    }
+
+    fn load(xs: &[f64]) -> v128 {
+        f64x2(xs[0], xs[1])
+    }
+
+    fn store(x: v128, xs: &mut [f64]) {
+        xs[0] = f64x2_extract_lane::<0>(x);
+        xs[1] = f64x2_extract_lane::<1>(x);
+    }
+
+    pub(super) fn run(
+        (mag, tan): (&mut [f64], &mut [f64]),
+        (rmag, rtan): (&[f64], &[f64]),
+        kernel: Kernel,
+        scalar: impl Fn(Phasor, Phasor) -> Phasor,
+    ) {
+        let n = mag.len() - mag.len() % LANES;
+
+        for i in (0..n).step_by(LANES) {
+            let (m, t) = kernel(
+                load(&mag[i..]),
+                load(&tan[i..]),
+                load(&rmag[i..]),
+                load(&rtan[i..]),
+            );
+
+            store(m, &mut mag[i..]);
+            store(t, &mut tan[i..]);
+        }
+
+        zip_with(
+            (&mut mag[n..], &mut tan[n..]),
+            (&rmag[n..], &rtan[n..]),
+            scalar,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arbitrary::any;
+    use crate::test_util::bits;
+    use proptest::collection::vec;
+    use test_strategy::proptest;
+
+    /// Representatives of every class of special values in [`crate::arbitrary`].
+    fn specials() -> Vec<f64> {
+        let nan = [
+            f64::NAN,
+            -f64::NAN,
+            f64::from_bits(0x7FF0_0000_0000_0001),
+            f64::from_bits(0xFFF4_0000_0000_0000),
+        ];
+
+        let infinite = [f64::INFINITY, f64::NEG_INFINITY];
+        let zero = [0f64, -0f64];
+        let subnormal = [f64::from_bits(1), f64::MIN_POSITIVE / 3f64];
+        let normal = [1f64, 0.5, 3f64, f64::MIN_POSITIVE, f64::MAX, 1E-300, 1E300];
+
+        let finite = zero.iter().chain(&subnormal).chain(&normal);
+        let signed = finite.flat_map(|&x| [x, -x]);
+
+        nan.into_iter().chain(infinite).chain(signed).collect()
+    }
+
+    fn assert_bit_identical(kernel: Kernel, scalar: fn(Phasor, Phasor) -> Phasor, ps: &[[f64; 4]]) {
+        let mut mag: Vec<_> = ps.iter().map(|p| p[0]).collect();
+        let mut tan: Vec<_> = ps.iter().map(|p| p[1]).collect();
+        let rmag: Vec<_> = ps.iter().map(|p| p[2]).collect();
+        let rtan: Vec<_> = ps.iter().map(|p| p[3]).collect();
+
+        let (mut smag, mut stan) = (mag.clone(), tan.clone());
+        zip_with((&mut smag, &mut stan), (&rmag, &rtan), scalar);
+        kernel((&mut mag, &mut tan), (&rmag, &rtan));
+
+        for (i, p) in ps.iter().enumerate() {
+            assert_eq!(bits([mag[i], tan[i]]), bits([smag[i], stan[i]]), "{p:?}");
+        }
+    }
+
+    fn exhaustive() -> Vec<[f64; 4]> {
+        let xs = specials();
+        let mut ps = Vec::new();
+
+        for &a in &xs {
+            for &b in &xs {
+                for &c in &xs {
+                    for &d in &xs {
+                        ps.push([a, b, c, d]);
+                    }
+                }
+            }
+        }
+
+        ps
+    }
+
+    #[test]
+    fn kernels_are_bit_identical_to_scalar_on_special_values() {
+        let ps = exhaustive();
+
+        assert_bit_identical(add, Add::add, &ps);
+        assert_bit_identical(mul, Mul::mul, &ps);
+        assert_bit_identical(div, Div::div, &ps);
+    }
+
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    fn kernels_are_bit_identical_to_scalar_on_special_values_in_the_browser() {
+        kernels_are_bit_identical_to_scalar_on_special_values()
+    }
+
+    #[proptest]
+    fn kernels_are_bit_identical_to_scalar(
+        #[strategy(vec((any(), any(), any(), any()), 0..64))] ps: Vec<(f64, f64, f64, f64)>,
+    ) {
+        let ps: Vec<_> = ps.into_iter().map(|(a, b, c, d)| [a, b, c, d]).collect();
+
+        assert_bit_identical(add, Add::add, &ps);
+        assert_bit_identical(mul, Mul::mul, &ps);
+        assert_bit_identical(div, Div::div, &ps);
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn trig_helpers_are_bit_identical_to_scalar_on_special_values() {
+        use crate::trig;
+        use avx2::tests::{binary, pair, unary};
+
+        if !is_x86_feature_detected!("avx2") {
+            return;
+        }
+
+        let xs = specials();
+        let pairs: Vec<_> = xs
+            .iter()
+            .flat_map(|&x| xs.iter().map(move |&y| (x, y)))
+            .collect();
+
+        // Every special value appears in every lane, as the number of pairs is a multiple of 4.
+        for chunk in pairs.chunks(4) {
+            let x = [0, 1, 2, 3].map(|i| chunk[i % chunk.len()].0);
+            let y = [0, 1, 2, 3].map(|i| chunk[i % chunk.len()].1);
+
+            let (sx, cx) = (unary(avx2::sinatan, x), unary(avx2::cosatan, x));
+            let (s, c) = (binary(avx2::sinatan2, x, y), binary(avx2::cosatan2, x, y));
+            let (ss, sc) = pair(avx2::tansubatan, x, y);
+            let (as_, ac) = pair(avx2::tanaddatan, x, y);
+
+            for i in 0..4 {
+                assert_eq!(bits([sx[i]]), bits([trig::sinatan(x[i])]), "{}", x[i]);
+                assert_eq!(bits([cx[i]]), bits([trig::cosatan(x[i])]), "{}", x[i]);
+
+                let (x, y) = (x[i], y[i]);
+                assert_eq!(bits([s[i]]), bits([trig::sinatan2(x, y)]), "{x}, {y}");
+                assert_eq!(bits([c[i]]), bits([trig::cosatan2(x, y)]), "{x}, {y}");
+
+                let (s, c) = trig::tansubatan(x, y);
+                assert_eq!(bits([ss[i], sc[i]]), bits([s, c]), "{x}, {y}");
+
+                let (s, c) = trig::tanaddatan(x, y);
+                assert_eq!(bits([as_[i], ac[i]]), bits([s, c]), "{x}, {y}");
+            }
+        }
+    }
+}
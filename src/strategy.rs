@@ -0,0 +1,150 @@
+//! [Proptest] strategies that generate [`Phasor`]s of particular classes.
+//!
+//! [Proptest]: https://docs.rs/proptest
+
+use crate::{arbitrary, Phasor};
+use proptest::prelude::*;
+
+fn phasor(
+    mag: impl Strategy<Value = f64>,
+    tan: impl Strategy<Value = f64>,
+) -> impl Strategy<Value = Phasor> {
+    (mag, tan).prop_map(|(mag, tan)| Phasor { mag, tan })
+}
+
+/// Any phasor whatsoever, including _NaN_.
+pub fn any() -> impl Strategy<Value = Phasor> {
+    phasor(arbitrary::any(), arbitrary::any())
+}
+
+/// Phasors whose magnitude or tangent (or both) is _NaN_.
+pub fn nan() -> impl Strategy<Value = Phasor> {
+    prop_oneof![
+        phasor(arbitrary::nan(), arbitrary::any()),
+        phasor(arbitrary::any(), arbitrary::nan()),
+    ]
+}
+
+/// Phasors that are not _NaN_.
+pub fn not_nan() -> impl Strategy<Value = Phasor> {
+    phasor(arbitrary::not_nan(), arbitrary::not_nan())
+}
+
+/// Phasors of infinite magnitude.
+pub fn infinite() -> impl Strategy<Value = Phasor> {
+    phasor(arbitrary::infinite(), arbitrary::not_nan())
+}
+
+/// Phasors that are neither _NaN_ nor infinite.
+pub fn finite() -> impl Strategy<Value = Phasor> {
+    phasor(arbitrary::finite(), arbitrary::not_nan())
+}
+
+/// Phasors of zero magnitude.
+pub fn zero() -> impl Strategy<Value = Phasor> {
+    phasor(arbitrary::zero(), arbitrary::not_nan())
+}
+
+/// Phasors that are neither _NaN_ nor zero.
+pub fn nonzero() -> impl Strategy<Value = Phasor> {
+    phasor(arbitrary::nonzero(), arbitrary::not_nan())
+}
+
+/// Phasors of [subnormal] magnitude.
+///
+/// [subnormal]: https://en.wikipedia.org/wiki/Denormal_number
+pub fn subnormal() -> impl Strategy<Value = Phasor> {
+    phasor(arbitrary::subnormal(), arbitrary::not_nan())
+}
+
+/// Phasors of normal magnitude.
+pub fn normal() -> impl Strategy<Value = Phasor> {
+    phasor(arbitrary::normal(), arbitrary::not_nan())
+}
+
+/// Phasors that are neither _NaN_, infinite nor zero.
+pub fn regular() -> impl Strategy<Value = Phasor> {
+    phasor(arbitrary::regular(), arbitrary::not_nan())
+}
+
+/// Purely real phasors.
+pub fn real() -> impl Strategy<Value = Phasor> {
+    phasor(arbitrary::not_nan(), arbitrary::zero())
+}
+
+/// Purely imaginary phasors.
+pub fn imaginary() -> impl Strategy<Value = Phasor> {
+    phasor(arbitrary::not_nan(), arbitrary::infinite())
+}
+
+/// Phasors of unit norm.
+pub fn unit() -> impl Strategy<Value = Phasor> {
+    phasor(prop_oneof![Just(1f64), Just(-1f64)], arbitrary::not_nan())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_strategy::proptest;
+
+    #[proptest]
+    fn nan_is_nan(#[strategy(nan())] p: Phasor) {
+        assert!(p.is_nan());
+    }
+
+    #[proptest]
+    fn not_nan_is_not_nan(#[strategy(not_nan())] p: Phasor) {
+        assert!(!p.is_nan());
+    }
+
+    #[proptest]
+    fn infinite_is_infinite(#[strategy(infinite())] p: Phasor) {
+        assert!(p.is_infinite());
+    }
+
+    #[proptest]
+    fn finite_is_finite(#[strategy(finite())] p: Phasor) {
+        assert!(p.is_finite());
+    }
+
+    #[proptest]
+    fn zero_is_zero(#[strategy(zero())] p: Phasor) {
+        assert!(p.is_zero());
+    }
+
+    #[proptest]
+    fn nonzero_is_neither_nan_nor_zero(#[strategy(nonzero())] p: Phasor) {
+        assert!(!p.is_nan());
+        assert!(!p.is_zero());
+    }
+
+    #[proptest]
+    fn subnormal_is_subnormal(#[strategy(subnormal())] p: Phasor) {
+        assert!(p.is_subnormal());
+    }
+
+    #[proptest]
+    fn normal_is_normal(#[strategy(normal())] p: Phasor) {
+        assert!(p.is_normal());
+    }
+
+    #[proptest]
+    fn regular_is_either_normal_or_subnormal(#[strategy(regular())] p: Phasor) {
+        assert!(p.is_normal() || p.is_subnormal());
+    }
+
+    #[proptest]
+    fn real_is_real(#[strategy(real())] p: Phasor) {
+        assert!(p.is_real());
+    }
+
+    #[proptest]
+    fn imaginary_is_imaginary(#[strategy(imaginary())] p: Phasor) {
+        assert!(p.is_imaginary());
+    }
+
+    #[proptest]
+    fn unit_has_unit_norm(#[strategy(unit())] p: Phasor) {
+        assert_eq!(p.norm(), 1f64);
+    }
+}
@@ -0,0 +1,20 @@
+use crate::Phasor;
+use std::borrow::Borrow;
+
+/// The magnitudes and tangents of `ps`, interleaved as in a batch buffer.
+pub(crate) fn flatten<P: Borrow<Phasor>>(ps: impl IntoIterator<Item = P>) -> Vec<f64> {
+    ps.into_iter()
+        .flat_map(|p| [p.borrow().mag, p.borrow().tan])
+        .collect()
+}
+
+/// The bit patterns of `xs`, with all _NaN_s identified, since Rust makes no guarantees about
+/// their sign or payload.
+pub(crate) fn bits<X: Borrow<f64>>(xs: impl IntoIterator<Item = X>) -> Vec<u64> {
+    xs.into_iter()
+        .map(|x| match *x.borrow() {
+            x if x.is_nan() => f64::NAN.to_bits(),
+            x => x.to_bits(),
+        })
+        .collect()
+}
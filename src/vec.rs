@@ -0,0 +1,480 @@
+use crate::{reduce, simd, Phasor};
+use std::iter::FromIterator;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+/// A growable sequence of [`Phasor`]s stored as separate contiguous arrays of magnitudes and
+/// tangents.
+///
+/// Elementwise operations are applied through the very same scalar implementation as that of
+/// [`Phasor`], so results match bit for bit, but the layout lets the compiler vectorize them.
+///
+/// Binary operations between two [`PhasorVec`]s panic if their lengths differ.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PhasorVec {
+    mag: Vec<f64>,
+    tan: Vec<f64>,
+}
+
+impl PhasorVec {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        PhasorVec {
+            mag: Vec::with_capacity(capacity),
+            tan: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Builds a [`PhasorVec`] from magnitudes and tangents, provided they are equally long.
+    pub fn from_parts(mag: Vec<f64>, tan: Vec<f64>) -> Option<Self> {
+        if mag.len() == tan.len() {
+            Some(PhasorVec { mag, tan })
+        } else {
+            None
+        }
+    }
+
+    pub fn into_parts(self) -> (Vec<f64>, Vec<f64>) {
+        (self.mag, self.tan)
+    }
+
+    pub fn mags(&self) -> &[f64] {
+        &self.mag
+    }
+
+    pub fn tans(&self) -> &[f64] {
+        &self.tan
+    }
+
+    pub fn len(&self) -> usize {
+        self.mag.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mag.is_empty()
+    }
+
+    pub fn push(&mut self, p: Phasor) {
+        self.mag.push(p.mag);
+        self.tan.push(p.tan);
+    }
+
+    pub fn get(&self, i: usize) -> Option<Phasor> {
+        Some(Phasor {
+            mag: *self.mag.get(i)?,
+            tan: self.tan[i],
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Phasor> + '_ {
+        self.mag
+            .iter()
+            .zip(&self.tan)
+            .map(|(&mag, &tan)| Phasor { mag, tan })
+    }
+
+    pub fn norm(&self) -> Vec<f64> {
+        self.iter().map(|p| p.norm()).collect()
+    }
+
+    /// The sum of every phasor, added in a fixed order.
+    pub fn sum(&self) -> Phasor {
+        reduce::sum(self.len(), |i| self.at(i))
+    }
+
+    /// The sum of the products of every phasor and its counterpart in `rhs`, without complex
+    /// conjugation, added in a fixed order.
+    pub fn dot(&self, rhs: &PhasorVec) -> Phasor {
+        assert_eq!(self.len(), rhs.len(), "phasor vectors differ in length");
+        reduce::sum(self.len(), |i| self.at(i) * rhs.at(i))
+    }
+
+    /// Parallel variant of [`PhasorVec::sum`], yielding exactly the same result.
+    #[cfg(feature = "rayon")]
+    pub fn par_sum(&self) -> Phasor {
+        reduce::par_sum(self.len(), |i| self.at(i))
+    }
+
+    /// Parallel variant of [`PhasorVec::dot`], yielding exactly the same result.
+    #[cfg(feature = "rayon")]
+    pub fn par_dot(&self, rhs: &PhasorVec) -> Phasor {
+        assert_eq!(self.len(), rhs.len(), "phasor vectors differ in length");
+        reduce::par_sum(self.len(), |i| self.at(i) * rhs.at(i))
+    }
+
+    fn at(&self, i: usize) -> Phasor {
+        Phasor {
+            mag: self.mag[i],
+            tan: self.tan[i],
+        }
+    }
+
+    pub fn exp(&self) -> Self {
+        self.map(Phasor::exp)
+    }
+
+    pub fn ln(&self) -> Self {
+        self.map(Phasor::ln)
+    }
+
+    pub fn conj(&self) -> Self {
+        self.map(Phasor::conj)
+    }
+
+    pub fn recip(&self) -> Self {
+        self.map(Phasor::recip)
+    }
+
+    pub fn exp_in_place(&mut self) {
+        self.map_in_place(Phasor::exp)
+    }
+
+    pub fn ln_in_place(&mut self) {
+        self.map_in_place(Phasor::ln)
+    }
+
+    pub fn conj_in_place(&mut self) {
+        self.map_in_place(Phasor::conj)
+    }
+
+    pub fn recip_in_place(&mut self) {
+        self.map_in_place(Phasor::recip)
+    }
+
+    fn map(&self, f: impl Fn(Phasor) -> Phasor) -> Self {
+        let mut v = self.clone();
+        v.map_in_place(f);
+        v
+    }
+
+    fn map_in_place(&mut self, f: impl Fn(Phasor) -> Phasor) {
+        for (mag, tan) in self.mag.iter_mut().zip(&mut self.tan) {
+            let p = f(Phasor {
+                mag: *mag,
+                tan: *tan,
+            });
+
+            *mag = p.mag;
+            *tan = p.tan;
+        }
+    }
+
+    fn zip_in_place(&mut self, rhs: &PhasorVec, f: impl Fn(Phasor, Phasor) -> Phasor) {
+        assert_eq!(self.len(), rhs.len(), "phasor vectors differ in length");
+
+        let lhs = self.mag.iter_mut().zip(&mut self.tan);
+        for ((mag, tan), q) in lhs.zip(rhs.iter()) {
+            let p = f(
+                Phasor {
+                    mag: *mag,
+                    tan: *tan,
+                },
+                q,
+            );
+
+            *mag = p.mag;
+            *tan = p.tan;
+        }
+    }
+
+    fn kernel_in_place(&mut self, rhs: &PhasorVec, kernel: simd::Kernel) {
+        assert_eq!(self.len(), rhs.len(), "phasor vectors differ in length");
+        kernel((&mut self.mag, &mut self.tan), (&rhs.mag, &rhs.tan))
+    }
+}
+
+macro_rules! impl_elementwise {
+    ($op:ident, $f:ident, $op_assign:ident, $f_assign:ident, $in_place:expr) => {
+        impl $op<&PhasorVec> for &PhasorVec {
+            type Output = PhasorVec;
+
+            fn $f(self, rhs: &PhasorVec) -> Self::Output {
+                let mut v = self.clone();
+                v.$f_assign(rhs);
+                v
+            }
+        }
+
+        impl $op<Phasor> for &PhasorVec {
+            type Output = PhasorVec;
+
+            fn $f(self, rhs: Phasor) -> Self::Output {
+                self.map(|p| $op::$f(p, rhs))
+            }
+        }
+
+        impl $op_assign<&PhasorVec> for PhasorVec {
+            fn $f_assign(&mut self, rhs: &PhasorVec) {
+                $in_place(self, rhs)
+            }
+        }
+
+        impl $op_assign<Phasor> for PhasorVec {
+            fn $f_assign(&mut self, rhs: Phasor) {
+                self.map_in_place(|p| $op::$f(p, rhs))
+            }
+        }
+    };
+}
+
+impl_elementwise!(Add, add, AddAssign, add_assign, |v: &mut PhasorVec, rhs| v
+    .kernel_in_place(rhs, simd::add));
+impl_elementwise!(Sub, sub, SubAssign, sub_assign, |v: &mut PhasorVec, rhs| v
+    .zip_in_place(rhs, Sub::sub));
+impl_elementwise!(Mul, mul, MulAssign, mul_assign, |v: &mut PhasorVec, rhs| v
+    .kernel_in_place(rhs, simd::mul));
+impl_elementwise!(Div, div, DivAssign, div_assign, |v: &mut PhasorVec, rhs| v
+    .kernel_in_place(rhs, simd::div));
+
+impl FromIterator<Phasor> for PhasorVec {
+    fn from_iter<I: IntoIterator<Item = Phasor>>(iter: I) -> Self {
+        let mut v = PhasorVec::new();
+        v.extend(iter);
+        v
+    }
+}
+
+impl Extend<Phasor> for PhasorVec {
+    fn extend<I: IntoIterator<Item = Phasor>>(&mut self, iter: I) {
+        for p in iter {
+            self.push(p);
+        }
+    }
+}
+
+impl From<&[Phasor]> for PhasorVec {
+    fn from(ps: &[Phasor]) -> Self {
+        ps.iter().copied().collect()
+    }
+}
+
+impl From<&PhasorVec> for Vec<Phasor> {
+    fn from(v: &PhasorVec) -> Self {
+        v.iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::{any, finite, not_nan};
+    use crate::test_util::{bits, flatten};
+    use approx::assert_ulps_eq;
+    use proptest::collection::vec;
+    use proptest::prop_assume;
+    use test_strategy::proptest;
+
+    #[proptest(cases = 16)]
+    fn sum_matches_batch_sum(#[strategy(vec(any(), 0..3000))] ps: Vec<Phasor>) {
+        let v = PhasorVec::from(&ps[..]);
+        let buf = flatten(&ps);
+        let s = crate::batch::sum(&buf, crate::batch::Layout::Phasor).unwrap();
+
+        assert_eq!(bits(flatten([v.sum()])), bits(flatten([s])));
+    }
+
+    #[proptest(cases = 16)]
+    fn dot_is_sum_of_products(
+        #[strategy(vec(finite(), 0..3000))] ps: Vec<Phasor>,
+        #[strategy(vec(finite(), #ps.len()))] qs: Vec<Phasor>,
+    ) {
+        let (v, w) = (PhasorVec::from(&ps[..]), PhasorVec::from(&qs[..]));
+        assert_eq!(bits(flatten([v.dot(&w)])), bits(flatten([(&v * &w).sum()])));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[proptest(cases = 16)]
+    fn parallel_reductions_match_sequential_reductions(
+        #[strategy(vec(any(), 0..3000))] ps: Vec<Phasor>,
+        #[strategy(vec(any(), #ps.len()))] qs: Vec<Phasor>,
+    ) {
+        let (v, w) = (PhasorVec::from(&ps[..]), PhasorVec::from(&qs[..]));
+
+        assert_eq!(bits(flatten([v.par_sum()])), bits(flatten([v.sum()])));
+        assert_eq!(bits(flatten([v.par_dot(&w)])), bits(flatten([v.dot(&w)])));
+    }
+
+    #[proptest]
+    fn round_trips_through_slices(#[strategy(vec(any(), 0..32))] ps: Vec<Phasor>) {
+        let v = PhasorVec::from(&ps[..]);
+
+        assert_eq!(v.len(), ps.len());
+        assert_eq!(
+            bits(flatten(Vec::from(&v))),
+            bits(flatten(ps.iter().copied()))
+        );
+        assert_eq!(v.get(ps.len()), None);
+    }
+
+    #[proptest]
+    fn from_parts_requires_equal_lengths(
+        #[strategy(vec(any(), 0..32))] ps: Vec<Phasor>,
+        extra: f64,
+    ) {
+        let v = PhasorVec::from(&ps[..]);
+        let (mut mag, tan) = v.clone().into_parts();
+
+        assert_eq!(
+            bits(flatten(
+                PhasorVec::from_parts(mag.clone(), tan.clone())
+                    .unwrap()
+                    .iter()
+            )),
+            bits(flatten(v.iter()))
+        );
+
+        mag.push(extra);
+        assert_eq!(PhasorVec::from_parts(mag, tan), None);
+    }
+
+    #[proptest]
+    fn binary_operations_match_scalar_bit_for_bit(
+        #[strategy(vec((any(), any()), 0..32))] pairs: Vec<(Phasor, Phasor)>,
+    ) {
+        let u: PhasorVec = pairs.iter().map(|&(p, _)| p).collect();
+        let v: PhasorVec = pairs.iter().map(|&(_, q)| q).collect();
+
+        assert_eq!(
+            bits(flatten((&u + &v).iter())),
+            bits(flatten(pairs.iter().map(|&(p, q)| p + q)))
+        );
+        assert_eq!(
+            bits(flatten((&u - &v).iter())),
+            bits(flatten(pairs.iter().map(|&(p, q)| p - q)))
+        );
+        assert_eq!(
+            bits(flatten((&u * &v).iter())),
+            bits(flatten(pairs.iter().map(|&(p, q)| p * q)))
+        );
+        assert_eq!(
+            bits(flatten((&u / &v).iter())),
+            bits(flatten(pairs.iter().map(|&(p, q)| p / q)))
+        );
+    }
+
+    #[proptest]
+    fn in_place_operations_match_scalar_bit_for_bit(
+        #[strategy(vec((any(), any()), 0..32))] pairs: Vec<(Phasor, Phasor)>,
+    ) {
+        let u: PhasorVec = pairs.iter().map(|&(p, _)| p).collect();
+        let v: PhasorVec = pairs.iter().map(|&(_, q)| q).collect();
+
+        let mut w = u.clone();
+        w += &v;
+        assert_eq!(bits(flatten(w.iter())), bits(flatten((&u + &v).iter())));
+
+        let mut w = u.clone();
+        w -= &v;
+        assert_eq!(bits(flatten(w.iter())), bits(flatten((&u - &v).iter())));
+
+        let mut w = u.clone();
+        w *= &v;
+        assert_eq!(bits(flatten(w.iter())), bits(flatten((&u * &v).iter())));
+
+        let mut w = u.clone();
+        w /= &v;
+        assert_eq!(bits(flatten(w.iter())), bits(flatten((&u / &v).iter())));
+    }
+
+    #[proptest]
+    fn scalar_broadcast_matches_scalar_bit_for_bit(
+        #[strategy(vec(any(), 0..32))] ps: Vec<Phasor>,
+        #[strategy(any())] q: Phasor,
+    ) {
+        let u = PhasorVec::from(&ps[..]);
+
+        assert_eq!(
+            bits(flatten((&u + q).iter())),
+            bits(flatten(ps.iter().map(|&p| p + q)))
+        );
+        assert_eq!(
+            bits(flatten((&u - q).iter())),
+            bits(flatten(ps.iter().map(|&p| p - q)))
+        );
+        assert_eq!(
+            bits(flatten((&u * q).iter())),
+            bits(flatten(ps.iter().map(|&p| p * q)))
+        );
+        assert_eq!(
+            bits(flatten((&u / q).iter())),
+            bits(flatten(ps.iter().map(|&p| p / q)))
+        );
+
+        let mut w = u.clone();
+        w *= q;
+        assert_eq!(bits(flatten(w.iter())), bits(flatten((&u * q).iter())));
+    }
+
+    #[proptest]
+    fn unary_operations_match_scalar_bit_for_bit(#[strategy(vec(any(), 0..32))] ps: Vec<Phasor>) {
+        let u = PhasorVec::from(&ps[..]);
+
+        assert_eq!(
+            bits(flatten(u.exp().iter())),
+            bits(flatten(ps.iter().map(|p| p.exp())))
+        );
+        assert_eq!(
+            bits(flatten(u.ln().iter())),
+            bits(flatten(ps.iter().map(|p| p.ln())))
+        );
+        assert_eq!(
+            bits(flatten(u.conj().iter())),
+            bits(flatten(ps.iter().map(|p| p.conj())))
+        );
+        assert_eq!(
+            bits(flatten(u.recip().iter())),
+            bits(flatten(ps.iter().map(|p| p.recip())))
+        );
+
+        let norms: Vec<_> = ps.iter().map(|p| p.norm().to_bits()).collect();
+        assert_eq!(
+            u.norm().iter().map(|n| n.to_bits()).collect::<Vec<_>>(),
+            norms
+        );
+
+        let mut w = u.clone();
+        w.exp_in_place();
+        assert_eq!(bits(flatten(w.iter())), bits(flatten(u.exp().iter())));
+
+        let mut w = u.clone();
+        w.ln_in_place();
+        assert_eq!(bits(flatten(w.iter())), bits(flatten(u.ln().iter())));
+
+        let mut w = u.clone();
+        w.conj_in_place();
+        assert_eq!(bits(flatten(w.iter())), bits(flatten(u.conj().iter())));
+
+        let mut w = u.clone();
+        w.recip_in_place();
+        assert_eq!(bits(flatten(w.iter())), bits(flatten(u.recip().iter())));
+    }
+
+    #[proptest]
+    fn addition_is_commutative(
+        #[strategy(vec((finite(), finite()), 0..32))] pairs: Vec<(Phasor, Phasor)>,
+    ) {
+        let u: PhasorVec = pairs.iter().map(|&(p, _)| p).collect();
+        let v: PhasorVec = pairs.iter().map(|&(_, q)| q).collect();
+
+        for (p, q) in (&u + &v).iter().zip((&v + &u).iter()) {
+            assert_ulps_eq!(p, q);
+        }
+    }
+
+    #[proptest]
+    fn conjugation_is_an_involution(#[strategy(vec(not_nan(), 0..32))] ps: Vec<Phasor>) {
+        let u = PhasorVec::from(&ps[..]);
+        assert_eq!(u.conj().conj(), u);
+    }
+
+    #[proptest]
+    fn mismatched_lengths_panic(#[strategy(vec(any(), 1..32))] ps: Vec<Phasor>) {
+        let u = PhasorVec::from(&ps[..]);
+        let v = PhasorVec::from(&ps[1..]);
+        prop_assume!(u.len() != v.len());
+
+        assert!(std::panic::catch_unwind(|| &u + &v).is_err());
+    }
+}
@@ -0,0 +1,109 @@
+#include "phasor.h"
+
+#include <math.h>
+#include <stdio.h>
+#include <string.h>
+
+static const double PI = 3.14159265358979323846;
+
+static int failures = 0;
+
+#define CHECK(cond)                                                                                \
+  do {                                                                                             \
+    if (!(cond)) {                                                                                 \
+      fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #cond);                     \
+      ++failures;                                                                                  \
+    }                                                                                              \
+  } while (0)
+
+#define CLOSE(x, y) CHECK(fabs((x) - (y)) <= 1e-12 * fmax(1.0, fabs(y)))
+
+static void arithmetic(void) {
+  Phasor p = phasor_rect(3.0, 4.0);
+  Phasor q = phasor_polar(2.0, PI / 2);
+
+  CLOSE(phasor_real(p), 3.0);
+  CLOSE(phasor_imag(p), 4.0);
+  CLOSE(phasor_norm(p), 5.0);
+  CLOSE(phasor_angle(q), PI / 2);
+
+  CLOSE(phasor_imag(phasor_add(p, q)), 6.0);
+  CLOSE(phasor_real(phasor_sub(p, q)), 3.0);
+  CLOSE(phasor_norm(phasor_mul(p, q)), 10.0);
+  CLOSE(phasor_norm(phasor_div(p, q)), 2.5);
+  CLOSE(phasor_imag(phasor_neg(p)), -4.0);
+  CLOSE(phasor_imag(phasor_conj(p)), -4.0);
+  CLOSE(phasor_norm(phasor_recip(p)), 0.2);
+  CLOSE(phasor_norm(phasor_sqrt(phasor_rect(-4.0, 0.0))), 2.0);
+  CLOSE(phasor_angle(phasor_ln(phasor_rect(-1.0, 0.0))), PI / 2);
+  CLOSE(phasor_imag(phasor_ln(phasor_rect(-1.0, 0.0))), PI);
+  CLOSE(phasor_real(phasor_exp(phasor_rect(0.0, PI))), -1.0);
+  CLOSE(phasor_real(phasor_log(phasor_rect(100.0, 0.0), 10.0)), 2.0);
+  CLOSE(phasor_real(phasor_cosh(phasor_rect(0.0, 0.0))), 1.0);
+  CLOSE(phasor_real(phasor_sinh(phasor_rect(0.0, 0.0))), 0.0);
+}
+
+static void classification(void) {
+  CHECK(phasor_classify(phasor_rect(1.0, 1.0)) == PHASOR_CATEGORY_NORMAL);
+  CHECK(phasor_classify(phasor_rect(0.0, 0.0)) == PHASOR_CATEGORY_ZERO);
+  CHECK(phasor_classify(phasor_rect(5e-324, 0.0)) == PHASOR_CATEGORY_SUBNORMAL);
+  CHECK(phasor_classify(phasor_rect(INFINITY, 0.0)) == PHASOR_CATEGORY_INFINITE);
+  CHECK(phasor_classify(phasor_rect(NAN, 0.0)) == PHASOR_CATEGORY_NAN);
+}
+
+static void checked(void) {
+  Phasor out = phasor_rect(7.0, 0.0);
+
+  CHECK(phasor_checked_div(phasor_rect(1.0, 0.0), phasor_rect(2.0, 0.0), &out) == PHASOR_STATUS_OK);
+  CLOSE(phasor_real(out), 0.5);
+
+  CHECK(phasor_checked_div(phasor_rect(1.0, 0.0), phasor_rect(0.0, 0.0), &out) ==
+        PHASOR_STATUS_DIVISION_BY_ZERO);
+  CLOSE(phasor_real(out), 0.5);
+
+  CHECK(phasor_checked_add(phasor_rect(NAN, 0.0), phasor_rect(1.0, 0.0), &out) ==
+        PHASOR_STATUS_NAN_OPERAND);
+  CHECK(phasor_checked_sub(phasor_rect(INFINITY, 0.0), phasor_rect(INFINITY, 0.0), &out) ==
+        PHASOR_STATUS_INDETERMINATE);
+  CHECK(phasor_checked_exp(phasor_rect(1e3, 0.0), &out) == PHASOR_STATUS_OVERFLOW);
+  CHECK(phasor_checked_ln(phasor_rect(0.0, 0.0), &out) == PHASOR_STATUS_DIVISION_BY_ZERO);
+  CHECK(phasor_checked_log(phasor_rect(8.0, 0.0), 2.0, &out) == PHASOR_STATUS_OK);
+  CLOSE(phasor_real(out), 3.0);
+}
+
+static void formatting(void) {
+  Phasor p = phasor_rect(1.0, 2.0);
+  PhasorFormat format = phasor_format_default();
+  char buf[64];
+
+  format.notation = PHASOR_NOTATION_RECT;
+  format.precision = 2;
+  CHECK(phasor_format(p, &format, buf, sizeof buf) == strlen("1.00+2.00i"));
+  CHECK(strcmp(buf, "1.00+2.00i") == 0);
+
+  format.notation = PHASOR_NOTATION_POLAR;
+  format.unit = PHASOR_ANGLE_UNIT_DEGREES;
+  format.precision = 0;
+  phasor_format(phasor_polar(2.0, PI / 2), &format, buf, sizeof buf);
+  CHECK(strcmp(buf, "2∠90°") == 0);
+
+  size_t n = phasor_format(phasor_rect(-1.0, 0.0), NULL, NULL, 0);
+  CHECK(n == strlen("1∠3.141592653589793"));
+
+  CHECK(phasor_format(phasor_rect(-1.0, 0.0), NULL, buf, n + 1) == n);
+  CHECK(strcmp(buf, "1∠3.141592653589793") == 0);
+
+  /* "∠" takes 3 bytes, which must not be split. */
+  memset(buf, 'x', sizeof buf);
+  CHECK(phasor_format(phasor_rect(-1.0, 0.0), NULL, buf, 3) == n);
+  CHECK(strcmp(buf, "1") == 0);
+  CHECK(buf[3] == 'x');
+}
+
+int main(void) {
+  arithmetic();
+  classification();
+  checked();
+  formatting();
+  return failures == 0 ? 0 : 1;
+}
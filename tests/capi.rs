@@ -0,0 +1,56 @@
+//! Compiles `tests/capi.c` against `include/phasor.h`, links it to the `cdylib` and runs it.
+
+#![cfg(unix)]
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Builds the `cdylib`, which `cargo test` doesn't do on its own, and returns its directory.
+fn cdylib() -> PathBuf {
+    let target = Path::new(env!("CARGO_TARGET_TMPDIR")).join("cdylib");
+
+    // Instrumentation flags, e.g. for sanitizers, are meaningless to the C compiler.
+    let status = Command::new(env!("CARGO"))
+        .args(["build", "--lib", "--features", "capi", "--manifest-path"])
+        .arg(Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target)
+        .env_remove("RUSTFLAGS")
+        .status()
+        .expect("failed to run cargo");
+
+    assert!(status.success(), "failed to build the cdylib");
+    target.join("debug")
+}
+
+#[test]
+fn c_program() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let exe = Path::new(env!("CARGO_TARGET_TMPDIR")).join("capi");
+    let lib = cdylib();
+
+    let status = Command::new(env::var_os("CC").unwrap_or_else(|| "cc".into()))
+        .args(["-std=c11", "-Wall", "-Wextra", "-Werror", "-pedantic"])
+        .arg("-I")
+        .arg(root.join("include"))
+        .arg(root.join("tests/capi.c"))
+        .arg("-o")
+        .arg(&exe)
+        .arg("-L")
+        .arg(&lib)
+        .args(["-lphasor", "-lm"])
+        .status()
+        .expect("failed to run the C compiler");
+
+    assert!(status.success(), "failed to compile tests/capi.c");
+
+    // Cargo points the search path at its own target directory, where the cdylib may be stale.
+    let status = Command::new(&exe)
+        .env("LD_LIBRARY_PATH", &lib)
+        .env("DYLD_LIBRARY_PATH", &lib)
+        .status()
+        .unwrap();
+
+    assert!(status.success(), "tests/capi.c failed");
+}
@@ -0,0 +1,348 @@
+//! Builds the WebAssembly component, loads it in wasmtime and checks it against the native library.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+use phasor::{AngleUnit, Format, Notation, Phasor, PhasorError};
+use std::num::FpCategory;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::OnceLock;
+use wasmtime::component::{Component, Linker, ResourceTable};
+use wasmtime::{Engine, Store};
+use wasmtime_wasi::{IoView, WasiCtx, WasiCtxBuilder, WasiView};
+
+mod wit {
+    wasmtime::component::bindgen!({
+        path: "wit",
+        world: "phasor",
+    });
+}
+
+use wit::exports::phasor::phasor::operations::Guest as Operations;
+use wit::phasor::phasor::types;
+
+/// Builds the component for `wasm32-wasip2`, which `cargo test` doesn't do on its own.
+fn component() -> PathBuf {
+    let target = Path::new(env!("CARGO_TARGET_TMPDIR")).join("component");
+
+    // Instrumentation flags for the host are meaningless to the guest.
+    let status = Command::new(env!("CARGO"))
+        .args(["build", "--lib", "--target", "wasm32-wasip2"])
+        .args(["--features", "component", "--manifest-path"])
+        .arg(Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target)
+        .env_remove("RUSTFLAGS")
+        .status()
+        .expect("failed to run cargo");
+
+    assert!(status.success(), "failed to build the component");
+    target.join("wasm32-wasip2/debug/phasor.wasm")
+}
+
+struct Host {
+    ctx: WasiCtx,
+    table: ResourceTable,
+}
+
+impl IoView for Host {
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.table
+    }
+}
+
+impl WasiView for Host {
+    fn ctx(&mut self) -> &mut WasiCtx {
+        &mut self.ctx
+    }
+}
+
+struct Guest {
+    store: Store<Host>,
+    bindings: wit::Phasor,
+}
+
+impl Guest {
+    fn new() -> Self {
+        static COMPONENT: OnceLock<(Engine, Component)> = OnceLock::new();
+
+        let (engine, component) = COMPONENT.get_or_init(|| {
+            let engine = Engine::default();
+            let component = Component::from_file(&engine, component()).unwrap();
+            (engine, component)
+        });
+
+        let mut linker = Linker::new(engine);
+        wasmtime_wasi::add_to_linker_sync(&mut linker).unwrap();
+
+        let host = Host {
+            ctx: WasiCtxBuilder::new().build(),
+            table: ResourceTable::new(),
+        };
+
+        let mut store = Store::new(engine, host);
+        let bindings = wit::Phasor::instantiate(&mut store, component, &linker).unwrap();
+        Guest { store, bindings }
+    }
+
+    fn operations(&mut self) -> (&Operations, &mut Store<Host>) {
+        (self.bindings.phasor_phasor_operations(), &mut self.store)
+    }
+}
+
+fn to_wit(p: Phasor) -> types::Phasor {
+    types::Phasor {
+        mag: p.mag,
+        tan: p.tan,
+    }
+}
+
+fn from_wit(p: types::Phasor) -> Phasor {
+    Phasor {
+        mag: p.mag,
+        tan: p.tan,
+    }
+}
+
+/// Whether two floats are the same up to the last few bits, which may differ between the math
+/// library of the host and that of WASI, and where WebAssembly doesn't preserve the bits of NaN.
+fn same(a: f64, b: f64) -> bool {
+    // Maps the sign-magnitude representation onto two's complement, so that consecutive floats
+    // are consecutive integers.
+    let key = |x: f64| match x.to_bits() as i64 {
+        b if b < 0 => i64::MIN.wrapping_sub(b),
+        b => b,
+    };
+
+    (a.is_nan() && b.is_nan()) || (a.is_nan() == b.is_nan() && key(a).abs_diff(key(b)) <= 4)
+}
+
+#[track_caller]
+fn assert_same(p: Phasor, q: Phasor) {
+    assert!(same(p.mag, q.mag) && same(p.tan, q.tan), "{p:?} != {q:?}");
+}
+
+fn samples() -> Vec<Phasor> {
+    let values = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.5,
+        0.3,
+        7.0e2,
+        -4.0e-3,
+        2.0e-310,
+        1.0e300,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+
+    values
+        .iter()
+        .flat_map(|&mag| values.iter().map(move |&tan| Phasor { mag, tan }))
+        .collect()
+}
+
+fn category(c: FpCategory) -> types::Category {
+    match c {
+        FpCategory::Nan => types::Category::Nan,
+        FpCategory::Infinite => types::Category::Infinite,
+        FpCategory::Zero => types::Category::Zero,
+        FpCategory::Subnormal => types::Category::Subnormal,
+        FpCategory::Normal => types::Category::Normal,
+    }
+}
+
+fn checked_error(e: PhasorError) -> types::CheckedError {
+    match e {
+        PhasorError::NanOperand(_) => types::CheckedError::NanOperand,
+        PhasorError::Indeterminate(_) => types::CheckedError::Indeterminate,
+        PhasorError::DivisionByZero(_) => types::CheckedError::DivisionByZero,
+        PhasorError::Overflow(_) => types::CheckedError::Overflow,
+    }
+}
+
+#[track_caller]
+fn assert_checked(
+    r: Result<types::Phasor, types::CheckedError>,
+    expected: Result<Phasor, PhasorError>,
+) {
+    match (r, expected) {
+        (Ok(p), Ok(q)) => assert_same(from_wit(p), q),
+        (Err(e), Err(f)) => assert_eq!(e, checked_error(f)),
+        (r, expected) => panic!("{r:?} != {expected:?}"),
+    }
+}
+
+#[test]
+#[ignore = "requires the wasm32-wasip2 target"]
+fn constructors() {
+    let mut guest = Guest::new();
+    let (ops, store) = guest.operations();
+
+    for p in samples() {
+        let (a, b) = (p.mag, p.tan);
+        assert_same(
+            from_wit(ops.call_rect(&mut *store, a, b).unwrap()),
+            Phasor::rect(a, b),
+        );
+        assert_same(
+            from_wit(ops.call_polar(&mut *store, a, b).unwrap()),
+            Phasor::polar(a, b),
+        );
+    }
+}
+
+#[test]
+#[ignore = "requires the wasm32-wasip2 target"]
+fn accessors() {
+    let mut guest = Guest::new();
+    let (ops, store) = guest.operations();
+
+    for p in samples() {
+        let w = to_wit(p);
+        assert!(same(ops.call_real(&mut *store, w).unwrap(), p.real()));
+        assert!(same(ops.call_imag(&mut *store, w).unwrap(), p.imag()));
+        assert!(same(ops.call_norm(&mut *store, w).unwrap(), p.norm()));
+        assert!(same(ops.call_angle(&mut *store, w).unwrap(), p.angle()));
+        assert_eq!(
+            ops.call_classify(&mut *store, w).unwrap(),
+            category(p.classify())
+        );
+    }
+}
+
+#[test]
+#[ignore = "requires the wasm32-wasip2 target"]
+fn binary() {
+    let mut guest = Guest::new();
+    let (ops, store) = guest.operations();
+
+    for p in samples() {
+        for q in samples() {
+            let (w, v) = (to_wit(p), to_wit(q));
+            assert_same(from_wit(ops.call_add(&mut *store, w, v).unwrap()), p + q);
+            assert_same(from_wit(ops.call_sub(&mut *store, w, v).unwrap()), p - q);
+            assert_same(from_wit(ops.call_mul(&mut *store, w, v).unwrap()), p * q);
+            assert_same(from_wit(ops.call_div(&mut *store, w, v).unwrap()), p / q);
+
+            assert_checked(
+                ops.call_checked_add(&mut *store, w, v).unwrap(),
+                p.checked_add(q),
+            );
+            assert_checked(
+                ops.call_checked_sub(&mut *store, w, v).unwrap(),
+                p.checked_sub(q),
+            );
+            assert_checked(
+                ops.call_checked_mul(&mut *store, w, v).unwrap(),
+                p.checked_mul(q),
+            );
+            assert_checked(
+                ops.call_checked_div(&mut *store, w, v).unwrap(),
+                p.checked_div(q),
+            );
+        }
+    }
+}
+
+#[test]
+#[ignore = "requires the wasm32-wasip2 target"]
+fn unary() {
+    let mut guest = Guest::new();
+    let (ops, store) = guest.operations();
+
+    for p in samples() {
+        let w = to_wit(p);
+        assert_same(from_wit(ops.call_neg(&mut *store, w).unwrap()), -p);
+        assert_same(from_wit(ops.call_conj(&mut *store, w).unwrap()), p.conj());
+        assert_same(from_wit(ops.call_recip(&mut *store, w).unwrap()), p.recip());
+        assert_same(from_wit(ops.call_exp(&mut *store, w).unwrap()), p.exp());
+        assert_same(from_wit(ops.call_ln(&mut *store, w).unwrap()), p.ln());
+        assert_same(from_wit(ops.call_sqrt(&mut *store, w).unwrap()), p.sqrt());
+        assert_same(from_wit(ops.call_sinh(&mut *store, w).unwrap()), p.sinh());
+        assert_same(from_wit(ops.call_cosh(&mut *store, w).unwrap()), p.cosh());
+
+        assert_checked(
+            ops.call_checked_recip(&mut *store, w).unwrap(),
+            p.checked_recip(),
+        );
+        assert_checked(
+            ops.call_checked_exp(&mut *store, w).unwrap(),
+            p.checked_exp(),
+        );
+        assert_checked(ops.call_checked_ln(&mut *store, w).unwrap(), p.checked_ln());
+        assert_checked(
+            ops.call_checked_sinh(&mut *store, w).unwrap(),
+            p.checked_sinh(),
+        );
+        assert_checked(
+            ops.call_checked_cosh(&mut *store, w).unwrap(),
+            p.checked_cosh(),
+        );
+
+        for base in [0.0, 0.5, 2.0, 10.0, f64::INFINITY, f64::NAN] {
+            assert_same(
+                from_wit(ops.call_log(&mut *store, w, base).unwrap()),
+                p.log(base),
+            );
+            assert_checked(
+                ops.call_checked_log(&mut *store, w, base).unwrap(),
+                p.checked_log(base),
+            );
+        }
+    }
+}
+
+#[test]
+#[ignore = "requires the wasm32-wasip2 target"]
+fn format() {
+    let mut guest = Guest::new();
+    let (ops, store) = guest.operations();
+
+    let notations = [
+        (types::Notation::Polar, Notation::Polar),
+        (types::Notation::Rect, Notation::Rect),
+        (types::Notation::Exp, Notation::Exp),
+    ];
+
+    let units = [
+        (types::AngleUnit::Radians, AngleUnit::Radians),
+        (types::AngleUnit::Degrees, AngleUnit::Degrees),
+    ];
+
+    for p in samples() {
+        let w = to_wit(p);
+        assert_eq!(
+            ops.call_format(&mut *store, w, None).unwrap(),
+            p.to_string()
+        );
+
+        for (notation, n) in notations {
+            for (unit, u) in units {
+                for precision in [None, Some(0), Some(3)] {
+                    for engineering in [false, true] {
+                        let options = types::FormatOptions {
+                            notation,
+                            unit,
+                            precision,
+                            engineering,
+                        };
+
+                        let mut format = Format::default();
+                        format.notation = n;
+                        format.unit = u;
+                        format.precision = precision.map(|p| p as usize);
+                        format.engineering = engineering;
+
+                        assert_eq!(
+                            ops.call_format(&mut *store, w, Some(options)).unwrap(),
+                            p.format(format).to_string()
+                        );
+                    }
+                }
+            }
+        }
+    }
+}